@@ -43,6 +43,7 @@ pub fn use_declarations() -> Tokens {
             },
         };
         use std::borrow::Cow;
+        use std::time::Duration;
         use percent_encoding::percent_encode;
         use serde::Serialize;
     )
@@ -349,6 +349,43 @@ impl<'a> RequestBuilder<'a> {
         }
     }
 
+    /// Creates the AST for a builder fn to set a timeout for the API call
+    fn create_request_timeout_fn(field: &syn::Ident) -> syn::ImplItem {
+        let doc_attr = doc("Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished.");
+
+        syn::ImplItem {
+            ident: ident("request_timeout"),
+            vis: syn::Visibility::Public,
+            defaultness: syn::Defaultness::Final,
+            attrs: vec![doc_attr],
+            node: syn::ImplItemKind::Method(
+                syn::MethodSig {
+                    unsafety: syn::Unsafety::Normal,
+                    constness: syn::Constness::NotConst,
+                    abi: None,
+                    decl: syn::FnDecl {
+                        inputs: vec![
+                            syn::FnArg::SelfValue(syn::Mutability::Mutable),
+                            syn::FnArg::Captured(
+                                syn::Pat::Path(None, path_none("timeout")),
+                                syn::parse_type("Duration").unwrap(),
+                            ),
+                        ],
+                        output: syn::FunctionRetTy::Ty(code_gen::ty("Self")),
+                        variadic: false,
+                    },
+                    generics: generics_none(),
+                },
+                syn::Block {
+                    stmts: vec![
+                        syn::Stmt::Semi(Box::new(parse_expr(quote!(self.#field = Some(timeout))))),
+                        syn::Stmt::Expr(Box::new(parse_expr(quote!(self)))),
+                    ],
+                },
+            ),
+        }
+    }
+
     /// Creates the AST for a builder fn for a builder impl
     fn create_impl_fn(f: (&String, &Type)) -> syn::ImplItem {
         let name = valid_name(&f.0).to_lowercase();
@@ -404,6 +441,7 @@ impl<'a> RequestBuilder<'a> {
     /// creates the AST for a builder struct
     fn create_builder_struct(
         builder_name: &str,
+        endpoint_name: &str,
         endpoint: &ApiEndpoint,
         common_params: &BTreeMap<String, Type>,
         enum_builder: &EnumBuilder,
@@ -440,6 +478,16 @@ impl<'a> RequestBuilder<'a> {
             ty: syn::parse_type("HeaderMap").unwrap(),
         });
 
+        let request_timeout_field_ident = ident("request_timeout");
+
+        // add a field for the request timeout
+        fields.push(syn::Field {
+            ident: Some(request_timeout_field_ident.clone()),
+            vis: syn::Visibility::Inherited,
+            attrs: vec![],
+            ty: syn::parse_type("Option<Duration>").unwrap(),
+        });
+
         if supports_body {
             fields.push(syn::Field {
                 ident: Some(ident("body")),
@@ -466,6 +514,9 @@ impl<'a> RequestBuilder<'a> {
             endpoint.params.iter().map(Self::create_impl_fn).collect();
 
         builder_fns.push(Self::create_header_fn(&headers_field_ident));
+        builder_fns.push(Self::create_request_timeout_fn(
+            &request_timeout_field_ident,
+        ));
 
         // add a body impl if supported
         if supports_body {
@@ -544,6 +595,13 @@ impl<'a> RequestBuilder<'a> {
             api_name_for_docs
         ));
 
+        let endpoint_name = lit(endpoint_name);
+
+        let send_blocking_doc = lit(format!(
+            "Creates a synchronous call to the {} API that blocks until the response is received. Requires the `blocking` feature",
+            api_name_for_docs
+        ));
+
         quote! {
             #enum_struct
 
@@ -566,11 +624,18 @@ impl<'a> RequestBuilder<'a> {
                       let path = self.parts.url();
                       let method = #method_expr;
                       let headers = self.headers;
+                      let timeout = self.request_timeout;
                       let query_string = #query_string_expr;
                       let body = #body_expr;
-                      let response = self.client.send(method, &path, headers, query_string.as_ref(), body).await?;
+                      let response = self.client.endpoint(#endpoint_name).send(method, &path, headers, query_string.as_ref(), body, timeout).await?;
                       Ok(response)
                 }
+
+                #[cfg(feature = "blocking")]
+                #[doc = #send_blocking_doc]
+                pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+                      crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+                }
             }
         }
     }
@@ -689,8 +754,16 @@ impl<'a> RequestBuilder<'a> {
     /// builds the AST that represent the builder structs
     /// and the ctor function for the builder struct on the root/namespace client
     pub fn build(self) -> (Tokens, Tokens) {
+        // the name of the API in the REST API specs, used to name the endpoint in traces
+        let endpoint_name = if self.is_root_method {
+            self.name.to_string()
+        } else {
+            format!("{}.{}", self.namespace_name.to_snake_case(), self.name)
+        };
+
         let builder_struct = Self::create_builder_struct(
             self.builder_name,
+            &endpoint_name,
             self.endpoint,
             self.common_params,
             &self.enum_builder,
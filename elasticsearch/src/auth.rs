@@ -18,6 +18,27 @@
  */
 //! Authentication components
 
+use crate::{
+    client::Elasticsearch,
+    error::Error,
+    http::{
+        client::BoxFuture,
+        headers::{HeaderValue, AUTHORIZATION},
+        transport::Transport,
+    },
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::{
+    fmt,
+    fmt::Debug,
+    fs,
+    future::Future,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant, SystemTime},
+};
+
 /// Credentials for authentication
 #[derive(Debug, Clone)]
 pub enum Credentials {
@@ -44,21 +65,34 @@ pub enum ClientCertificate {
     /// as well any intermediate certificates that allow clients to build a chain to
     /// a trusted root. The chain certificates
     /// should be in order from the leaf certificate towards the root.
-    ///
-    /// # Optional
-    ///
-    /// This requires the `native-tls` feature to be enabled.
-    #[cfg(feature = "native-tls")]
     Pkcs12(Vec<u8>, Option<String>),
 
     /// Bytes of a PEM encoded private key and
     /// at least one PEM encoded certificate.
     ///
-    /// # Optional
-    ///
-    /// This requires the `rustls-tls` feature to be enabled.
-    #[cfg(feature = "rustls-tls")]
+    /// The private key may be an unencrypted PKCS#8 or PKCS#1 RSA key.
     Pem(Vec<u8>),
+
+    /// Bytes of at least one PEM encoded certificate, and bytes of a PEM encoded
+    /// private key, such as the `.crt` and `.key` files produced by `elasticsearch-certutil`.
+    ///
+    /// The private key may be an unencrypted PKCS#8 or PKCS#1 RSA key.
+    PemWithKey(Vec<u8>, Vec<u8>),
+}
+
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+impl ClientCertificate {
+    /// Reads a PEM encoded certificate file and a separate PEM encoded private key file
+    pub fn from_pem_files<P, K>(cert: P, key: K) -> std::io::Result<Self>
+    where
+        P: AsRef<Path>,
+        K: AsRef<Path>,
+    {
+        Ok(ClientCertificate::PemWithKey(
+            fs::read(cert)?,
+            fs::read(key)?,
+        ))
+    }
 }
 
 #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
@@ -67,3 +101,628 @@ impl From<ClientCertificate> for Credentials {
         Credentials::Certificate(cert)
     }
 }
+
+/// Provides the credentials used to authenticate each API call to Elasticsearch,
+/// for credentials that can change at runtime, such as secrets that are rotated.
+///
+/// [Credentials] are themselves a provider of fixed credentials. A closure that returns a
+/// future resolving to [Credentials] is a provider that is consulted for each API call.
+///
+/// # Example
+///
+/// Read an access token rotated by a secrets agent, at most once per minute
+///
+/// ```rust,no_run
+/// # use elasticsearch::{
+/// #     auth::{CachedCredentialsProvider, FileCredentialsProvider},
+/// #     http::transport::{SingleNodeConnectionPool, TransportBuilder},
+/// # };
+/// # use std::time::Duration;
+/// # fn doc() -> Result<(), Box<dyn std::error::Error>> {
+/// let provider = CachedCredentialsProvider::new(
+///     FileCredentialsProvider::bearer("/var/run/secrets/elasticsearch/token"),
+///     Duration::from_secs(60),
+/// );
+/// let transport = TransportBuilder::new(SingleNodeConnectionPool::default())
+///     .credentials_provider(provider)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait CredentialsProvider: Send + Sync {
+    /// Gets the credentials to use for an API call
+    fn credentials(&self) -> BoxFuture<'_, Result<Credentials, Error>>;
+
+    /// Refreshes the credentials after an API call is rejected with a `401 Unauthorized`
+    /// response, returning whether the credentials may have changed, in which case the
+    /// API call is sent once more.
+    ///
+    /// By default, credentials are not refreshed.
+    fn refresh(&self) -> BoxFuture<'_, Result<bool, Error>> {
+        Box::pin(async { Ok(false) })
+    }
+}
+
+impl CredentialsProvider for Credentials {
+    fn credentials(&self) -> BoxFuture<'_, Result<Credentials, Error>> {
+        let credentials = self.clone();
+        Box::pin(async move { Ok(credentials) })
+    }
+}
+
+impl<F, Fut> CredentialsProvider for F
+where
+    F: Fn() -> Fut + Send + Sync,
+    Fut: Future<Output = Result<Credentials, Error>> + Send + 'static,
+{
+    fn credentials(&self) -> BoxFuture<'_, Result<Credentials, Error>> {
+        Box::pin(self())
+    }
+
+    /// The closure is called for each API call, so the credentials may have changed
+    fn refresh(&self) -> BoxFuture<'_, Result<bool, Error>> {
+        Box::pin(async { Ok(true) })
+    }
+}
+
+/// The contents of a credentials file when it was last read
+struct FileContents {
+    modified: Option<SystemTime>,
+    checked: Instant,
+    contents: String,
+    credentials: Credentials,
+}
+
+/// Parses credentials from the contents of a file
+type ParseCredentials = dyn Fn(&str) -> Result<Credentials, Error> + Send + Sync;
+
+/// Default interval at which a [FileCredentialsProvider] checks whether its file changed
+pub static DEFAULT_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// A [CredentialsProvider] that reads credentials from a file, such as one written by a
+/// secrets agent. The file is read again when its modified time changes, and when
+/// credentials are rejected by Elasticsearch.
+///
+/// The credentials are cached, and the modified time of the file is checked at most once
+/// per check interval. The file is checked and read on the blocking thread pool of
+/// the tokio runtime, so API calls must be made within a tokio runtime.
+pub struct FileCredentialsProvider {
+    file: Arc<CredentialsFile>,
+    check_interval: Duration,
+}
+
+struct CredentialsFile {
+    path: PathBuf,
+    parse: Arc<ParseCredentials>,
+    cached: Mutex<Option<FileContents>>,
+}
+
+impl FileCredentialsProvider {
+    /// Creates a new instance of [FileCredentialsProvider] that reads the file at `path`,
+    /// and parses credentials from its contents with `parse`
+    pub fn new<P, F>(path: P, parse: F) -> Self
+    where
+        P: AsRef<Path>,
+        F: Fn(&str) -> Result<Credentials, Error> + Send + Sync + 'static,
+    {
+        Self {
+            file: Arc::new(CredentialsFile {
+                path: path.as_ref().to_path_buf(),
+                parse: Arc::new(parse),
+                cached: Mutex::new(None),
+            }),
+            check_interval: DEFAULT_CHECK_INTERVAL,
+        }
+    }
+
+    /// Creates a new instance of [FileCredentialsProvider] that reads Basic authentication
+    /// credentials from a file containing `username:password`
+    pub fn basic<P: AsRef<Path>>(path: P) -> Self {
+        Self::new(path, |contents| {
+            let (username, password) = split_pair(contents, "username:password")?;
+            Ok(Credentials::Basic(username, password))
+        })
+    }
+
+    /// Creates a new instance of [FileCredentialsProvider] that reads an access token
+    /// for Bearer authentication from a file
+    pub fn bearer<P: AsRef<Path>>(path: P) -> Self {
+        Self::new(path, |contents| {
+            Ok(Credentials::Bearer(contents.to_string()))
+        })
+    }
+
+    /// Creates a new instance of [FileCredentialsProvider] that reads API key credentials
+    /// from a file containing `id:api_key`
+    pub fn api_key<P: AsRef<Path>>(path: P) -> Self {
+        Self::new(path, |contents| {
+            let (id, api_key) = split_pair(contents, "id:api_key")?;
+            Ok(Credentials::ApiKey(id, api_key))
+        })
+    }
+
+    /// Sets the interval at which the modified time of the file is checked.
+    ///
+    /// Defaults to [DEFAULT_CHECK_INTERVAL]
+    pub fn check_interval(mut self, check_interval: Duration) -> Self {
+        self.check_interval = check_interval;
+        self
+    }
+
+    /// Reads the file on the blocking thread pool
+    async fn read_blocking(&self, force: bool) -> Result<(Credentials, bool), Error> {
+        let file = self.file.clone();
+        tokio::task::spawn_blocking(move || file.read(force))
+            .await
+            .map_err(|e| Error::lib(format!("failed to read credentials file: {}", e)))?
+    }
+}
+
+impl CredentialsFile {
+    /// The cached credentials, when the file was checked within the interval
+    fn fresh(&self, check_interval: Duration) -> Option<Credentials> {
+        match self.cached.lock().unwrap().as_ref() {
+            Some(c) if c.checked.elapsed() < check_interval => Some(c.credentials.clone()),
+            _ => None,
+        }
+    }
+
+    /// Reads the file when it has changed since it was last read,
+    /// returning whether the contents changed
+    fn read(&self, force: bool) -> Result<(Credentials, bool), Error> {
+        let modified = fs::metadata(&self.path)?.modified().ok();
+        let checked = Instant::now();
+        let mut cached = self.cached.lock().unwrap();
+        if let Some(c) = cached.as_mut() {
+            if !force && modified.is_some() && c.modified == modified {
+                c.checked = checked;
+                return Ok((c.credentials.clone(), false));
+            }
+        }
+
+        let contents = fs::read_to_string(&self.path)?.trim().to_string();
+        if let Some(c) = cached.as_mut() {
+            if c.contents == contents {
+                c.modified = modified;
+                c.checked = checked;
+                return Ok((c.credentials.clone(), false));
+            }
+        }
+
+        let credentials = (self.parse)(&contents)?;
+        *cached = Some(FileContents {
+            modified,
+            checked,
+            contents,
+            credentials: credentials.clone(),
+        });
+        Ok((credentials, true))
+    }
+}
+
+impl Debug for FileCredentialsProvider {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FileCredentialsProvider")
+            .field("path", &self.file.path)
+            .field("check_interval", &self.check_interval)
+            .finish()
+    }
+}
+
+impl CredentialsProvider for FileCredentialsProvider {
+    fn credentials(&self) -> BoxFuture<'_, Result<Credentials, Error>> {
+        Box::pin(async move {
+            if let Some(credentials) = self.file.fresh(self.check_interval) {
+                return Ok(credentials);
+            }
+            self.read_blocking(false)
+                .await
+                .map(|(credentials, _)| credentials)
+        })
+    }
+
+    /// Reads the file again, returning whether its contents changed
+    fn refresh(&self) -> BoxFuture<'_, Result<bool, Error>> {
+        Box::pin(async move { self.read_blocking(true).await.map(|(_, changed)| changed) })
+    }
+}
+
+/// Splits the contents of a credentials file of the form `a:b`
+fn split_pair(contents: &str, form: &str) -> Result<(String, String), Error> {
+    let mut parts = contents.splitn(2, ':');
+    match (parts.next(), parts.next()) {
+        (Some(a), Some(b)) if !a.is_empty() => Ok((a.to_string(), b.to_string())),
+        _ => Err(Error::lib(format!(
+            "credentials file should be of the form '{}'",
+            form
+        ))),
+    }
+}
+
+/// A [CredentialsProvider] that caches the credentials of another provider for a
+/// period of time. The cached credentials are discarded when they are rejected
+/// by Elasticsearch.
+pub struct CachedCredentialsProvider<P> {
+    provider: P,
+    ttl: Duration,
+    cached: Mutex<Option<(Credentials, Instant)>>,
+}
+
+impl<P> CachedCredentialsProvider<P>
+where
+    P: CredentialsProvider,
+{
+    /// Creates a new instance of [CachedCredentialsProvider] that caches the credentials
+    /// of `provider` for `ttl`
+    pub fn new(provider: P, ttl: Duration) -> Self {
+        Self {
+            provider,
+            ttl,
+            cached: Mutex::new(None),
+        }
+    }
+}
+
+impl<P> Debug for CachedCredentialsProvider<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CachedCredentialsProvider")
+            .field("ttl", &self.ttl)
+            .finish()
+    }
+}
+
+impl<P> CredentialsProvider for CachedCredentialsProvider<P>
+where
+    P: CredentialsProvider,
+{
+    fn credentials(&self) -> BoxFuture<'_, Result<Credentials, Error>> {
+        Box::pin(async move {
+            if let Some((credentials, expires)) = self.cached.lock().unwrap().as_ref() {
+                if *expires > Instant::now() {
+                    return Ok(credentials.clone());
+                }
+            }
+
+            let credentials = self.provider.credentials().await?;
+            *self.cached.lock().unwrap() = Some((credentials.clone(), Instant::now() + self.ttl));
+            Ok(credentials)
+        })
+    }
+
+    fn refresh(&self) -> BoxFuture<'_, Result<bool, Error>> {
+        Box::pin(async move {
+            self.cached.lock().unwrap().take();
+            self.provider.refresh().await
+        })
+    }
+}
+
+/// The default period of time before an access token expires, at which a
+/// [TokenCredentialsProvider] obtains a new access token
+pub static DEFAULT_TOKEN_REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+/// How a [TokenCredentialsProvider] initially obtains an access token
+#[derive(Clone)]
+enum TokenGrant {
+    Password(String, String),
+    RefreshToken(String),
+}
+
+/// The tokens obtained with the get token API
+struct Token {
+    access_token: String,
+    refresh_token: Option<String>,
+    refresh_at: Instant,
+}
+
+/// The lifetime of an access token, in seconds, when the get token API response does not
+/// include it, which is the default lifetime of access tokens in Elasticsearch
+static DEFAULT_TOKEN_EXPIRES_IN: u64 = 20 * 60;
+
+/// The subset of the get token API response used by [TokenCredentialsProvider]
+#[derive(Deserialize)]
+struct GetTokenResponse {
+    access_token: String,
+    #[serde(default = "default_expires_in")]
+    expires_in: u64,
+    refresh_token: Option<String>,
+}
+
+fn default_expires_in() -> u64 {
+    DEFAULT_TOKEN_EXPIRES_IN
+}
+
+/// A [CredentialsProvider] that authenticates API calls with a Bearer access token obtained
+/// with the [get token API](https://www.elastic.co/guide/en/elasticsearch/reference/current/security-api-get-token.html),
+/// so that a username and password are not sent with each API call.
+///
+/// The access token is refreshed using the refresh token returned alongside it, before the
+/// access token expires according to its `expires_in`, which defaults to 20 minutes when not
+/// returned, and when an API call is rejected with a `401 Unauthorized` response. When the
+/// access token cannot be refreshed, a new access token is obtained using the grant with
+/// which the provider was created.
+///
+/// Calls to the get token and invalidate token APIs are sent with a separate client, which
+/// needs credentials with the `manage_token` cluster privilege, except for the password grant,
+/// where these calls are authenticated with the username and password.
+///
+/// Clones of the provider share the tokens, so a clone can be kept to invalidate the tokens
+/// when the application shuts down.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use elasticsearch::{
+/// #     auth::TokenCredentialsProvider,
+/// #     http::transport::{SingleNodeConnectionPool, Transport, TransportBuilder},
+/// #     Elasticsearch,
+/// # };
+/// # async fn doc() -> Result<(), Box<dyn std::error::Error>> {
+/// let token_client = Elasticsearch::new(Transport::single_node("https://localhost:9200")?);
+/// let tokens = TokenCredentialsProvider::password(token_client, "elastic", "changeme");
+///
+/// let transport = TransportBuilder::new(SingleNodeConnectionPool::default())
+///     .credentials_provider(tokens.clone())
+///     .build()?;
+/// let client = Elasticsearch::new(transport);
+///
+/// // make API calls with client, then on shutdown
+/// tokens.invalidate().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct TokenCredentialsProvider {
+    client: Elasticsearch,
+    grant: TokenGrant,
+    refresh_margin: Duration,
+    token: Arc<tokio::sync::Mutex<Option<Token>>>,
+}
+
+impl TokenCredentialsProvider {
+    /// Creates a new instance of [TokenCredentialsProvider] that obtains an access token
+    /// using the password grant with the given username and password
+    pub fn password<U, P>(client: Elasticsearch, username: U, password: P) -> Self
+    where
+        U: Into<String>,
+        P: Into<String>,
+    {
+        Self::new(
+            client,
+            TokenGrant::Password(username.into(), password.into()),
+        )
+    }
+
+    /// Creates a new instance of [TokenCredentialsProvider] that obtains an access token
+    /// using the refresh token grant with the given refresh token
+    pub fn refresh_token<T>(client: Elasticsearch, refresh_token: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::new(client, TokenGrant::RefreshToken(refresh_token.into()))
+    }
+
+    fn new(client: Elasticsearch, grant: TokenGrant) -> Self {
+        Self {
+            client,
+            grant,
+            refresh_margin: DEFAULT_TOKEN_REFRESH_MARGIN,
+            token: Arc::new(tokio::sync::Mutex::new(None)),
+        }
+    }
+
+    /// The period of time before an access token expires, at which a new access token is
+    /// obtained. Defaults to [DEFAULT_TOKEN_REFRESH_MARGIN].
+    ///
+    /// When an access token expires sooner than this, a new access token is obtained
+    /// when half of its lifetime has elapsed.
+    pub fn refresh_margin(mut self, margin: Duration) -> Self {
+        self.refresh_margin = margin;
+        self
+    }
+
+    /// Invalidates the access token and refresh token, if any, with the
+    /// [invalidate token API](https://www.elastic.co/guide/en/elasticsearch/reference/current/security-api-invalidate-token.html).
+    ///
+    /// A subsequent API call obtains a new access token using the grant with which the
+    /// provider was created, so this should be called when the application shuts down.
+    pub async fn invalidate(&self) -> Result<(), Error> {
+        let token = match self.token.lock().await.take() {
+            Some(t) => t,
+            None => return Ok(()),
+        };
+
+        self.invalidate_token(json!({ "token": token.access_token }))
+            .await?;
+        if let Some(refresh_token) = token.refresh_token {
+            self.invalidate_token(json!({ "refresh_token": refresh_token }))
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn invalidate_token(&self, body: Value) -> Result<(), Error> {
+        let security = self.client.security();
+        let mut request = security.invalidate_token().body(body);
+        if let Some(value) = self.grant_authorization() {
+            request = request.header(AUTHORIZATION, value);
+        }
+        request.send().await?.error_for_status_code()?;
+        Ok(())
+    }
+
+    /// Obtains a new token, using the refresh token of the current token if there is one,
+    /// falling back to the grant with which the provider was created
+    async fn obtain(&self, current: Option<&Token>) -> Result<Token, Error> {
+        if let Some(refresh_token) = current.and_then(|t| t.refresh_token.as_ref()) {
+            let body = json!({ "grant_type": "refresh_token", "refresh_token": refresh_token });
+            if let Ok(token) = self.get_token(body).await {
+                return Ok(token);
+            }
+        }
+
+        let body = match &self.grant {
+            TokenGrant::Password(username, password) => json!({
+                "grant_type": "password",
+                "username": username,
+                "password": password
+            }),
+            TokenGrant::RefreshToken(refresh_token) => json!({
+                "grant_type": "refresh_token",
+                "refresh_token": refresh_token
+            }),
+        };
+        self.get_token(body).await
+    }
+
+    async fn get_token(&self, body: Value) -> Result<Token, Error> {
+        let security = self.client.security();
+        let mut request = security.get_token().body(body);
+        if let Some(value) = self.grant_authorization() {
+            request = request.header(AUTHORIZATION, value);
+        }
+
+        let now = Instant::now();
+        let response = request
+            .send()
+            .await?
+            .error_for_status_code()?
+            .json::<GetTokenResponse>()
+            .await?;
+
+        let lifetime = Duration::from_secs(response.expires_in);
+        let refresh_in = if lifetime > self.refresh_margin {
+            lifetime - self.refresh_margin
+        } else {
+            lifetime / 2
+        };
+
+        Ok(Token {
+            access_token: response.access_token,
+            refresh_token: response.refresh_token,
+            refresh_at: now + refresh_in,
+        })
+    }
+
+    /// The Authorization header with which to call the token APIs, for the password grant
+    fn grant_authorization(&self) -> Option<HeaderValue> {
+        match &self.grant {
+            TokenGrant::Password(username, password) => {
+                Transport::authorization(&Credentials::Basic(username.clone(), password.clone()))
+            }
+            TokenGrant::RefreshToken(_) => None,
+        }
+    }
+}
+
+impl Debug for TokenCredentialsProvider {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TokenCredentialsProvider")
+            .field("refresh_margin", &self.refresh_margin)
+            .finish()
+    }
+}
+
+impl CredentialsProvider for TokenCredentialsProvider {
+    fn credentials(&self) -> BoxFuture<'_, Result<Credentials, Error>> {
+        Box::pin(async move {
+            let mut token = self.token.lock().await;
+            let valid = match token.as_ref() {
+                Some(t) => t.refresh_at > Instant::now(),
+                None => false,
+            };
+            if !valid {
+                *token = Some(self.obtain(token.as_ref()).await?);
+            }
+
+            let access_token = token.as_ref().map(|t| t.access_token.clone());
+            Ok(Credentials::Bearer(access_token.unwrap_or_default()))
+        })
+    }
+
+    /// Marks the access token as due to be refreshed on the next API call
+    fn refresh(&self) -> BoxFuture<'_, Result<bool, Error>> {
+        Box::pin(async move {
+            if let Some(t) = self.token.lock().await.as_mut() {
+                t.refresh_at = Instant::now();
+            }
+            Ok(true)
+        })
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::auth::{
+        CachedCredentialsProvider, Credentials, CredentialsProvider, FileCredentialsProvider,
+    };
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+    use std::time::Duration;
+
+    fn token(credentials: Credentials) -> String {
+        match credentials {
+            Credentials::Bearer(t) => t,
+            c => panic!("unexpected credentials {:?}", c),
+        }
+    }
+
+    #[tokio::test]
+    async fn file_provider_reads_changed_file() -> Result<(), failure::Error> {
+        let path = std::env::temp_dir().join(format!(
+            "elasticsearch-rs-credentials-{}",
+            std::process::id()
+        ));
+        std::fs::write(&path, "id:key\n")?;
+        let provider = FileCredentialsProvider::api_key(&path);
+
+        match provider.credentials().await? {
+            Credentials::ApiKey(id, key) => assert_eq!((id.as_str(), key.as_str()), ("id", "key")),
+            c => panic!("unexpected credentials {:?}", c),
+        }
+        assert!(!provider.refresh().await?);
+
+        std::fs::write(&path, "id:rotated")?;
+        assert!(provider.refresh().await?);
+        match provider.credentials().await? {
+            Credentials::ApiKey(_, key) => assert_eq!(key, "rotated"),
+            c => panic!("unexpected credentials {:?}", c),
+        }
+
+        std::fs::write(&path, "invalid")?;
+        assert!(provider.refresh().await.is_err());
+
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cached_provider_caches_until_refreshed() -> Result<(), failure::Error> {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let c = calls.clone();
+        let provider = CachedCredentialsProvider::new(
+            move || {
+                let n = c.fetch_add(1, Ordering::SeqCst);
+                async move { Ok(Credentials::Bearer(format!("token-{}", n))) }
+            },
+            Duration::from_secs(60),
+        );
+
+        assert_eq!(token(provider.credentials().await?), "token-0");
+        assert_eq!(token(provider.credentials().await?), "token-0");
+        assert!(provider.refresh().await?);
+        assert_eq!(token(provider.credentials().await?), "token-1");
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn static_credentials_are_not_refreshed() -> Result<(), failure::Error> {
+        let credentials = Credentials::Bearer("token".into());
+        assert!(!credentials.refresh().await?);
+        assert_eq!(token(credentials.credentials().await?), "token");
+        Ok(())
+    }
+}
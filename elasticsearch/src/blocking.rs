@@ -0,0 +1,239 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! Blocking (synchronous) client
+//!
+//! Requires the `blocking` feature. The blocking client makes API calls that block the
+//! current thread until the response is received, for use in applications that do not
+//! otherwise use an async runtime. API calls are executed on a runtime internal to the
+//! client, so the blocking client must not be used from within an async context.
+//!
+//! The API functions of the blocking [Elasticsearch] client return the same builders as the
+//! async client, with a `send_blocking()` function to make the API call.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use elasticsearch::{blocking::Elasticsearch, SearchParts};
+//! # use serde_json::{json, Value};
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Elasticsearch::default();
+//! let response = client
+//!     .search(SearchParts::Index(&["tweets"]))
+//!     .body(json!({
+//!         "query": {
+//!             "match_all": {}
+//!         }
+//!     }))
+//!     .send_blocking()?;
+//!
+//! let response_body = response.json::<Value>()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    error::Error,
+    http::{
+        headers::HeaderMap,
+        request::{Body, RequestOptions},
+        response::Response as AsyncResponse,
+        transport::Transport,
+        Method, StatusCode, Url,
+    },
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{future::Future, ops::Deref};
+use tokio::runtime::{Builder, Runtime};
+
+lazy_static! {
+    static ref RUNTIME: Runtime = Builder::new()
+        .threaded_scheduler()
+        .enable_all()
+        .thread_name("elasticsearch-blocking")
+        .build()
+        .expect("blocking client runtime builds");
+}
+
+/// Runs the future to completion on the runtime of the blocking client, blocking the
+/// current thread
+pub(crate) fn block_on<F>(future: F) -> F::Output
+where
+    F: Future,
+{
+    RUNTIME.handle().block_on(future)
+}
+
+/// Runs the future in the background on the runtime of the blocking client
+pub(crate) fn spawn<F>(future: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    RUNTIME.handle().spawn(future);
+}
+
+/// Blocking root client for top level APIs.
+///
+/// Dereferences to the async [Elasticsearch](crate::Elasticsearch) client, whose API
+/// functions return builders with a `send_blocking()` function.
+#[derive(Clone, Debug, Default)]
+pub struct Elasticsearch {
+    client: crate::Elasticsearch,
+}
+
+impl Elasticsearch {
+    /// Creates a new instance of the blocking root client
+    pub fn new(transport: Transport) -> Self {
+        Self {
+            client: crate::Elasticsearch::new(transport),
+        }
+    }
+
+    /// Creates a synchronous request that blocks until the response is received
+    ///
+    /// Accepts the HTTP method and relative path to an API,
+    /// and optional query string and body.
+    pub fn send<B, Q>(
+        &self,
+        method: Method,
+        path: &str,
+        headers: HeaderMap,
+        query_string: Option<&Q>,
+        body: Option<B>,
+    ) -> Result<Response, Error>
+    where
+        B: Body,
+        Q: Serialize + ?Sized,
+    {
+        block_on(self.client.send(method, path, headers, query_string, body)).map(Response::new)
+    }
+
+    /// Creates a synchronous request with [RequestOptions], such as a request timeout,
+    /// that blocks until the response is received
+    pub fn send_with_options<B, Q>(
+        &self,
+        method: Method,
+        path: &str,
+        headers: HeaderMap,
+        query_string: Option<&Q>,
+        body: Option<B>,
+        options: &RequestOptions,
+    ) -> Result<Response, Error>
+    where
+        B: Body,
+        Q: Serialize + ?Sized,
+    {
+        block_on(
+            self.client
+                .send_with_options(method, path, headers, query_string, body, options),
+        )
+        .map(Response::new)
+    }
+}
+
+impl Deref for Elasticsearch {
+    type Target = crate::Elasticsearch;
+
+    fn deref(&self) -> &Self::Target {
+        &self.client
+    }
+}
+
+impl From<crate::Elasticsearch> for Elasticsearch {
+    fn from(client: crate::Elasticsearch) -> Self {
+        Self { client }
+    }
+}
+
+/// A response from Elasticsearch, whose body is read synchronously
+#[derive(Debug)]
+pub struct Response(AsyncResponse);
+
+impl Response {
+    /// Creates a new instance of a blocking response from an async response
+    pub fn new(response: AsyncResponse) -> Self {
+        Self(response)
+    }
+
+    /// Get the response content-length, if known.
+    pub fn content_length(&self) -> Option<u64> {
+        self.0.content_length()
+    }
+
+    /// Gets the response content-type.
+    pub fn content_type(&self) -> &str {
+        self.0.content_type()
+    }
+
+    /// Turn the response into an [Error] if Elasticsearch returned an error.
+    pub fn error_for_status_code(self) -> Result<Self, Error> {
+        self.0.error_for_status_code().map(Self)
+    }
+
+    /// Turn the response into an [Error] if Elasticsearch returned an error.
+    pub fn error_for_status_code_ref(&self) -> Result<&Self, Error> {
+        self.0.error_for_status_code_ref().map(|_| self)
+    }
+
+    /// Reads the response body as JSON, blocking until it is read
+    ///
+    /// Reading the response body consumes `self`
+    pub fn json<B>(self) -> Result<B, Error>
+    where
+        B: DeserializeOwned,
+    {
+        block_on(self.0.json())
+    }
+
+    /// Gets the response headers.
+    pub fn headers(&self) -> &HeaderMap {
+        self.0.headers()
+    }
+
+    /// Gets the request method.
+    pub fn method(&self) -> Method {
+        self.0.method()
+    }
+
+    /// Get the HTTP status code of the response
+    pub fn status_code(&self) -> StatusCode {
+        self.0.status_code()
+    }
+
+    /// Reads the response body as plain text, blocking until it is read
+    ///
+    /// Reading the response body consumes `self`
+    pub fn text(self) -> Result<String, Error> {
+        block_on(self.0.text())
+    }
+
+    /// Gets the request URL
+    pub fn url(&self) -> &Url {
+        self.0.url()
+    }
+
+    /// Gets the Deprecation warning response headers
+    pub fn warning_headers(&self) -> impl Iterator<Item = &str> {
+        self.0.warning_headers()
+    }
+
+    /// Converts into the async response
+    pub fn into_async(self) -> AsyncResponse {
+        self.0
+    }
+}
@@ -17,7 +17,242 @@
  * under the License.
  */
 //! Certificate components
-pub use reqwest::Certificate;
+
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+use crate::auth::ClientCertificate;
+use crate::{error::Error, http::transport::BuildError};
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+use std::sync::Arc;
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// An X509 certificate, used to validate the certificate provided by the server
+/// when establishing a HTTPS connection.
+///
+/// Several certificates can be loaded from a PEM bundle, such as the certificates of an old
+/// and a new Certificate Authority (CA) while the CA of a cluster is rotated
+///
+/// ```rust,no_run
+/// # use elasticsearch::cert::Certificate;
+/// # fn doc() -> Result<(), Box<dyn std::error::Error>> {
+/// let bundle = Certificate::from_pem_bundle_file("ca_bundle.pem")?;
+///
+/// let old_ca = Certificate::from_pem(&std::fs::read("old_ca.crt")?)?;
+/// let new_ca = Certificate::from_pem(&std::fs::read("new_ca.crt")?)?;
+/// let both = vec![old_ca, new_ca];
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+#[derive(Clone)]
+pub struct Certificate {
+    der: Vec<u8>,
+}
+
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+impl Certificate {
+    /// Creates a certificate from a binary DER encoded certificate
+    pub fn from_der(der: &[u8]) -> Result<Certificate, Error> {
+        Ok(Certificate::parse_der(der)?)
+    }
+
+    /// Creates a certificate from the first PEM encoded certificate in a PEM file
+    pub fn from_pem(pem: &[u8]) -> Result<Certificate, Error> {
+        Ok(Certificate::parse_pem_bundle(pem)?.remove(0))
+    }
+
+    /// Creates certificates from all of the PEM encoded certificates in a PEM file,
+    /// such as a bundle of Certificate Authority (CA) certificates
+    pub fn from_pem_bundle(pem: &[u8]) -> Result<Vec<Certificate>, Error> {
+        Ok(Certificate::parse_pem_bundle(pem)?)
+    }
+
+    /// Reads certificates from a PEM file, as with [Certificate::from_pem_bundle]
+    pub fn from_pem_bundle_file<P: AsRef<Path>>(path: P) -> Result<Vec<Certificate>, Error> {
+        Certificate::from_pem_bundle(&fs::read(path)?)
+    }
+
+    fn parse_der(der: &[u8]) -> Result<Certificate, BuildError> {
+        reqwest::Certificate::from_der(der).map_err(BuildError::cert)?;
+        Ok(Certificate { der: der.to_vec() })
+    }
+
+    pub(crate) fn parse_pem_bundle(pem: &[u8]) -> Result<Vec<Certificate>, BuildError> {
+        let ders = pem_certificates(pem)
+            .map_err(|e| BuildError::Tls(format!("failed to parse certificate: {}", e)))?;
+        if ders.is_empty() {
+            return Err(BuildError::Tls(
+                "failed to parse certificate: no PEM encoded certificate found".into(),
+            ));
+        }
+
+        ders.iter().map(|der| Certificate::parse_der(der)).collect()
+    }
+}
+
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+impl fmt::Debug for Certificate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Certificate").finish()
+    }
+}
+
+/// The default period of time between checks of the files watched by a [CertificateReload]
+pub static DEFAULT_CERTIFICATE_RELOAD_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Client certificate and Certificate Authority (CA) files that a
+/// [Transport](crate::http::transport::Transport) watches, rebuilding its HTTP client
+/// when they change, such as when certificates are re-issued.
+///
+/// The files are read when the transport is built, and checked for changes on a background
+/// thread once per [CertificateReload::interval], until the transport is dropped. When they have
+/// changed, subsequent API calls are sent with a new HTTP client, while API calls in flight
+/// complete with the previous client.
+/// If the changed files cannot be loaded, such as while they are being written, the previous
+/// client continues to be used, and the files are checked again after the interval.
+///
+/// # Optional
+///
+/// This requires the `native-tls` or `rustls-tls` feature to be enabled.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use elasticsearch::{
+/// #     cert::CertificateReload,
+/// #     http::transport::{SingleNodeConnectionPool, TransportBuilder},
+/// #     Elasticsearch,
+/// # };
+/// # use std::time::Duration;
+/// # use url::Url;
+/// # fn doc() -> Result<(), Box<dyn std::error::Error>> {
+/// let conn_pool = SingleNodeConnectionPool::new(Url::parse("https://example.com")?);
+/// let reload = CertificateReload::new()
+///     .client_certificate("/etc/certs/tls.crt", "/etc/certs/tls.key")
+///     .ca_certificate("/etc/certs/ca.crt")
+///     .interval(Duration::from_secs(30));
+/// let transport = TransportBuilder::new(conn_pool)
+///     .reload_certificates(reload)
+///     .build()?;
+/// let client = Elasticsearch::new(transport);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+#[derive(Debug, Clone)]
+pub struct CertificateReload {
+    client_cert: Option<(PathBuf, PathBuf)>,
+    ca_cert: Option<PathBuf>,
+    interval: Duration,
+}
+
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+impl Default for CertificateReload {
+    fn default() -> Self {
+        Self {
+            client_cert: None,
+            ca_cert: None,
+            interval: DEFAULT_CERTIFICATE_RELOAD_INTERVAL,
+        }
+    }
+}
+
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+impl CertificateReload {
+    /// Creates a new instance of [CertificateReload] that watches no files
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A PEM encoded client certificate file and PEM encoded private key file, used for
+    /// PKI authentication in place of any [Credentials::Certificate](crate::auth::Credentials::Certificate)
+    pub fn client_certificate<C, K>(mut self, cert: C, key: K) -> Self
+    where
+        C: Into<PathBuf>,
+        K: Into<PathBuf>,
+    {
+        self.client_cert = Some((cert.into(), key.into()));
+        self
+    }
+
+    /// A PEM file of one or more CA certificates, which replace the certificates of
+    /// [CertificateValidation::Full] or [CertificateValidation::FullBundle], applied as
+    /// [CertificateValidation::FullBundle], or of `CertificateValidation::Certificate` or
+    /// `CertificateValidation::CertificateBundle`, applied as
+    /// `CertificateValidation::CertificateBundle`. Any other validation is replaced with
+    /// [CertificateValidation::FullBundle].
+    pub fn ca_certificate<P: Into<PathBuf>>(mut self, ca: P) -> Self {
+        self.ca_cert = Some(ca.into());
+        self
+    }
+
+    /// The period of time between checks of the files for changes.
+    /// Defaults to [DEFAULT_CERTIFICATE_RELOAD_INTERVAL].
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    pub(crate) fn check_interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Reads the contents of the watched files
+    pub(crate) fn read(&self) -> Result<CertificateFiles, BuildError> {
+        let client_cert = match &self.client_cert {
+            Some((cert, key)) => Some((fs::read(cert)?, fs::read(key)?)),
+            None => None,
+        };
+        let ca_cert = match &self.ca_cert {
+            Some(ca) => Some(fs::read(ca)?),
+            None => None,
+        };
+        Ok(CertificateFiles {
+            client_cert,
+            ca_cert,
+        })
+    }
+}
+
+/// The contents of the files watched by a [CertificateReload]
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+#[derive(PartialEq)]
+pub(crate) struct CertificateFiles {
+    client_cert: Option<(Vec<u8>, Vec<u8>)>,
+    ca_cert: Option<Vec<u8>>,
+}
+
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+impl CertificateFiles {
+    /// Replaces the client certificate and the certificates of the validation with those
+    /// loaded from the files
+    pub(crate) fn apply(
+        &self,
+        client_cert: &mut Option<ClientCertificate>,
+        validation: &mut Option<CertificateValidation>,
+    ) -> Result<(), BuildError> {
+        if let Some((cert, key)) = &self.client_cert {
+            *client_cert = Some(ClientCertificate::PemWithKey(cert.clone(), key.clone()));
+        }
+
+        if let Some(ca) = &self.ca_cert {
+            let ca = Certificate::parse_pem_bundle(ca)?;
+            *validation = Some(match validation.take() {
+                #[cfg(feature = "native-tls")]
+                Some(CertificateValidation::Certificate(_))
+                | Some(CertificateValidation::CertificateBundle(_)) => {
+                    CertificateValidation::CertificateBundle(ca)
+                }
+                _ => CertificateValidation::FullBundle(ca),
+            });
+        }
+
+        Ok(())
+    }
+}
 
 /// Validation applied to a SSL/TLS certificate, to establish a HTTPS connection.
 ///
@@ -108,6 +343,37 @@ let _response = client.ping().send().await?;
 ```
 "##
 )]
+/// ## Fingerprint validation
+///
+/// This requires the `native-tls`, or `rustls-tls` feature to be enabled.
+///
+/// With Elasticsearch running at `https://example.com`, configured to use a certificate
+/// whose SHA-256 fingerprint is known, such as the fingerprint of the CA certificate printed
+/// by Elasticsearch when security is automatically configured
+#[cfg_attr(
+    any(feature = "native-tls", feature = "rustls-tls"),
+    doc = r##"
+```rust,norun
+# use elasticsearch::{
+#     cert::CertificateValidation,
+#     Error, Elasticsearch,
+#     http::transport::{TransportBuilder,SingleNodeConnectionPool},
+# };
+# use url::Url;
+# async fn doc() -> Result<(), Box<dyn std::error::Error>> {
+let url = Url::parse("https://example.com")?;
+let conn_pool = SingleNodeConnectionPool::new(url);
+let fingerprint = "A5:21:F2:27:63:3F:D3:83:CE:5A:71:6D:7A:A0:DF:8F:F8:D9:1F:E6:3A:31:F8:BB:9B:7E:6E:59:A0:82:36:F1";
+let transport = TransportBuilder::new(conn_pool)
+    .cert_validation(CertificateValidation::Fingerprint(fingerprint.into()))
+    .build()?;
+let client = Elasticsearch::new(transport);
+let _response = client.ping().send().await?;
+# Ok(())
+# }
+```
+"##
+)]
 /// ## No validation
 ///
 /// No validation is performed on the certificate provided by the server.
@@ -134,6 +400,7 @@ let _response = client.ping().send().await?;
 /// # Ok(())
 /// # }
 /// ```
+#[derive(Clone)]
 pub enum CertificateValidation {
     /// Default validation of the certificate, which validates that the certificate provided by the
     /// server is signed by a trusted Certificate Authority (CA) and also verifies that the server’s hostname
@@ -160,6 +427,20 @@ pub enum CertificateValidation {
     /// This requires the `native-tls`, or `rustls-tls` feature to be enabled.
     #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
     Full(Certificate),
+    /// Full validation of the certificate against several trusted Certificate Authority (CA)
+    /// certificates, such as those of a PEM bundle loaded with [Certificate::from_pem_bundle].
+    /// The certificate provided by the server must be signed by one of them, and its hostname
+    /// verified, as with [CertificateValidation::Full].
+    ///
+    /// This is useful when rotating the CA that signs the certificates of a cluster, during
+    /// which the certificates are signed by the old or the new CA. The root certificates of
+    /// the operating system are also trusted unless disabled with
+    /// [TransportBuilder::tls_built_in_root_certs](crate::http::transport::TransportBuilder::tls_built_in_root_certs).
+    ///
+    /// # Optional
+    /// This requires the `native-tls`, or `rustls-tls` feature to be enabled.
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    FullBundle(Vec<Certificate>),
     /// Validates that the certificate provided by the server is signed by a trusted
     /// Certificate Authority (CA), but does not perform hostname verification.
     ///
@@ -175,6 +456,35 @@ pub enum CertificateValidation {
     /// This requires the `native-tls` feature to be enabled.
     #[cfg(feature = "native-tls")]
     Certificate(Certificate),
+    /// Validates that the certificate provided by the server is signed by one of several
+    /// trusted Certificate Authority (CA) certificates, such as those of a PEM bundle loaded
+    /// with [Certificate::from_pem_bundle], but does not perform hostname verification, as
+    /// with `CertificateValidation::Certificate`.
+    ///
+    /// # Optional
+    ///
+    /// This requires the `native-tls` feature to be enabled.
+    #[cfg(feature = "native-tls")]
+    CertificateBundle(Vec<Certificate>),
+    /// Validates the certificate chain provided by the server against the certificate in it
+    /// whose SHA-256 fingerprint matches the given fingerprint. That certificate is the only
+    /// trusted Certificate Authority (CA): the server certificate must be signed by it, directly
+    /// or through the intermediate certificates in the chain, and its CommonName (CN) or a
+    /// Subject Alternative Name (SAN) must match the server hostname. When the fingerprint is
+    /// that of the server certificate itself, the certificate is accepted as is.
+    ///
+    /// The fingerprint is a hex encoded string, optionally with bytes separated by colons,
+    /// as output by `openssl x509 -fingerprint -sha256`. This is useful for pinning the
+    /// certificate of a cluster, or the CA that signed it, without distributing the certificate
+    /// to clients.
+    ///
+    /// # Optional
+    ///
+    /// This requires the `native-tls`, or `rustls-tls` feature to be enabled. Connections
+    /// validated by fingerprint always use `rustls`, including when the `native-tls` feature
+    /// is enabled.
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    Fingerprint(String),
     /// No validation is performed on the certificate provided by the server.
     ///
     /// This disables many of the security benefits of SSL/TLS and should only be used after very
@@ -182,3 +492,655 @@ pub enum CertificateValidation {
     /// attempting to resolve TLS errors, and **its use on production clusters is strongly discouraged**.
     None,
 }
+
+/// The DER encoded certificates of a PEM file
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+fn pem_certificates(pem: &[u8]) -> Result<Vec<Vec<u8>>, String> {
+    let certs = rustls::internal::pemfile::certs(&mut &*pem)
+        .map_err(|_| "invalid PEM encoded certificate".to_string())?;
+    Ok(certs.into_iter().map(|cert| cert.0).collect())
+}
+
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+fn client_cert_error(err: &str) -> BuildError {
+    BuildError::Tls(format!("failed to parse client certificate: {}", err))
+}
+
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+fn client_key_error(err: &str) -> BuildError {
+    BuildError::Tls(format!(
+        "failed to parse client certificate private key: {}",
+        err
+    ))
+}
+
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+enum Identity {
+    /// A DER-formatted PKCS#12 archive and passphrase
+    Pkcs12(Vec<u8>, String),
+    /// DER encoded certificates, and a DER encoded PKCS#8 private key
+    Pem(Vec<Vec<u8>>, Vec<u8>),
+}
+
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+impl Identity {
+    fn parse(cert: &ClientCertificate) -> Result<Self, BuildError> {
+        match cert {
+            ClientCertificate::Pkcs12(b, p) => {
+                Ok(Identity::Pkcs12(b.clone(), p.clone().unwrap_or_default()))
+            }
+            ClientCertificate::Pem(b) => Identity::pem(b, b),
+            ClientCertificate::PemWithKey(c, k) => Identity::pem(c, k),
+        }
+    }
+
+    /// Parses PEM encoded certificates and a PEM encoded PKCS#8 or PKCS#1 RSA private key,
+    /// as produced by `elasticsearch-certutil`. A PKCS#1 key is wrapped in a PKCS#8
+    /// `PrivateKeyInfo`, which both TLS backends accept.
+    fn pem(certs: &[u8], key: &[u8]) -> Result<Self, BuildError> {
+        use rustls::internal::pemfile;
+
+        let certs = pem_certificates(certs).map_err(|e| client_cert_error(&e))?;
+        if certs.is_empty() {
+            return Err(client_cert_error("no PEM encoded certificate found"));
+        }
+
+        let pkcs8 = pemfile::pkcs8_private_keys(&mut &*key)
+            .ok()
+            .and_then(|keys| keys.into_iter().next());
+        let key = match pkcs8 {
+            Some(key) => key.0,
+            None => {
+                let pkcs1 = pemfile::rsa_private_keys(&mut &*key)
+                    .ok()
+                    .and_then(|keys| keys.into_iter().next())
+                    .ok_or_else(|| {
+                        client_key_error("no unencrypted PKCS#8 or PKCS#1 RSA private key found")
+                    })?;
+                rsa_private_key_info(&pkcs1.0).map_err(|e| client_key_error(&e.to_string()))?
+            }
+        };
+
+        Ok(Identity::Pem(certs, key))
+    }
+}
+
+/// Wraps a DER encoded PKCS#1 RSA private key in a DER encoded PKCS#8 `PrivateKeyInfo`
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+fn rsa_private_key_info(pkcs1: &[u8]) -> Result<Vec<u8>, pkcs8::der::Error> {
+    use pkcs8::der::{asn1::AnyRef, Encode};
+
+    let algorithm = pkcs8::AlgorithmIdentifierRef {
+        oid: pkcs8::ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1"),
+        parameters: Some(AnyRef::NULL),
+    };
+    pkcs8::PrivateKeyInfo::new(algorithm, pkcs1).to_der()
+}
+
+/// The TLS settings that implement a [CertificateValidation]
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+#[derive(Default)]
+struct TlsOptions {
+    roots: Vec<Certificate>,
+    accept_invalid_certs: bool,
+    #[cfg(feature = "native-tls")]
+    accept_invalid_hostnames: bool,
+    disable_built_in_roots: bool,
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    fingerprint: Option<String>,
+}
+
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+impl From<CertificateValidation> for TlsOptions {
+    fn from(validation: CertificateValidation) -> Self {
+        match validation {
+            CertificateValidation::Default => TlsOptions::default(),
+            CertificateValidation::Full(cert) => TlsOptions {
+                roots: vec![cert],
+                ..Default::default()
+            },
+            CertificateValidation::FullBundle(certs) => TlsOptions {
+                roots: certs,
+                ..Default::default()
+            },
+            #[cfg(feature = "native-tls")]
+            CertificateValidation::Certificate(cert) => TlsOptions {
+                roots: vec![cert],
+                accept_invalid_hostnames: true,
+                ..Default::default()
+            },
+            #[cfg(feature = "native-tls")]
+            CertificateValidation::CertificateBundle(certs) => TlsOptions {
+                roots: certs,
+                accept_invalid_hostnames: true,
+                ..Default::default()
+            },
+            #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+            CertificateValidation::Fingerprint(fingerprint) => TlsOptions {
+                fingerprint: Some(fingerprint),
+                ..Default::default()
+            },
+            CertificateValidation::None => TlsOptions {
+                accept_invalid_certs: true,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Configures the TLS backend of the HTTP client to validate the certificate provided by
+/// the server, and to authenticate with the client certificate, if any.
+///
+/// The HTTP client only accepts PKCS#12 client certificates with `native-tls`, and always
+/// trusts the built-in root certificates, so a connector is built here for PEM client
+/// certificates, for trusting only the given root certificates, and for fingerprint validation.
+/// `rustls` is used when the `native-tls` feature is not enabled, and for fingerprint
+/// validation, which requires a custom certificate verifier.
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+pub(crate) fn configure_tls(
+    builder: reqwest::ClientBuilder,
+    validation: CertificateValidation,
+    built_in_root_certs: bool,
+    client_cert: Option<&ClientCertificate>,
+) -> Result<reqwest::ClientBuilder, BuildError> {
+    let mut options = TlsOptions::from(validation);
+    options.disable_built_in_roots = !built_in_root_certs;
+    let identity = client_cert.map(Identity::parse).transpose()?;
+
+    if options.fingerprint.is_some() || cfg!(not(feature = "native-tls")) {
+        let config = rustls_config(&options, identity.as_ref())?;
+        return Ok(builder.use_preconfigured_tls(config));
+    }
+
+    #[cfg(feature = "native-tls")]
+    {
+        configure_native_tls(builder, &options, identity.as_ref())
+    }
+
+    #[cfg(not(feature = "native-tls"))]
+    {
+        unreachable!("rustls is used when the native-tls feature is not enabled")
+    }
+}
+
+/// Configures the HTTP client to use `native-tls`
+#[cfg(feature = "native-tls")]
+fn configure_native_tls(
+    mut builder: reqwest::ClientBuilder,
+    options: &TlsOptions,
+    identity: Option<&Identity>,
+) -> Result<reqwest::ClientBuilder, BuildError> {
+    if identity.is_some() || options.disable_built_in_roots {
+        let connector = native_tls_connector(options, identity)?;
+        return Ok(builder.use_preconfigured_tls(connector));
+    }
+
+    for root in &options.roots {
+        builder = builder.add_root_certificate(
+            reqwest::Certificate::from_der(&root.der).map_err(BuildError::cert)?,
+        );
+    }
+
+    Ok(builder
+        .danger_accept_invalid_certs(options.accept_invalid_certs)
+        .danger_accept_invalid_hostnames(options.accept_invalid_hostnames))
+}
+
+/// Builds a native-tls connector, authenticating with the client certificate, if any
+#[cfg(feature = "native-tls")]
+fn native_tls_connector(
+    options: &TlsOptions,
+    identity: Option<&Identity>,
+) -> Result<native_tls_crate::TlsConnector, BuildError> {
+    let mut tls = native_tls_crate::TlsConnector::builder();
+    tls.danger_accept_invalid_certs(options.accept_invalid_certs)
+        .danger_accept_invalid_hostnames(options.accept_invalid_hostnames)
+        .disable_built_in_roots(options.disable_built_in_roots);
+
+    for root in &options.roots {
+        let cert = native_tls_crate::Certificate::from_der(&root.der)
+            .map_err(|e| BuildError::Tls(format!("failed to load certificate: {}", e)))?;
+        tls.add_root_certificate(cert);
+    }
+
+    match identity {
+        Some(Identity::Pkcs12(der, password)) => {
+            let identity = native_tls_crate::Identity::from_pkcs12(der, password).map_err(|e| {
+                BuildError::Tls(format!("failed to load PKCS#12 client certificate: {}", e))
+            })?;
+            tls.identity(identity);
+        }
+        Some(Identity::Pem(certs, key)) => {
+            let identity = native_tls_identity(certs, key).map_err(|e| {
+                BuildError::Tls(format!("failed to load PEM client certificate: {}", e))
+            })?;
+            tls.identity(identity);
+        }
+        None => {}
+    }
+
+    tls.build().map_err(|e| BuildError::Tls(e.to_string()))
+}
+
+/// Loads a client certificate with native-tls, which accepts PEM encoded certificates and
+/// a PEM encoded PKCS#8 private key
+#[cfg(feature = "native-tls")]
+fn native_tls_identity(
+    certs: &[Vec<u8>],
+    key: &[u8],
+) -> Result<native_tls_crate::Identity, Box<dyn std::error::Error>> {
+    use pkcs8::der::pem::{encode_string, LineEnding};
+
+    let mut pem = String::new();
+    for cert in certs {
+        pem.push_str(
+            &encode_string("CERTIFICATE", LineEnding::LF, cert).map_err(pkcs8::der::Error::from)?,
+        );
+    }
+    let key = encode_string("PRIVATE KEY", LineEnding::LF, key).map_err(pkcs8::der::Error::from)?;
+    Ok(native_tls_crate::Identity::from_pkcs8(
+        pem.as_bytes(),
+        key.as_bytes(),
+    )?)
+}
+
+/// Parses a hex encoded SHA-256 fingerprint, ignoring colon separators and case
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+fn parse_fingerprint(fingerprint: &str) -> Result<Vec<u8>, BuildError> {
+    let hex: Vec<char> = fingerprint
+        .chars()
+        .filter(|c| *c != ':' && !c.is_whitespace())
+        .collect();
+
+    let invalid = || BuildError::Tls(format!("invalid SHA-256 fingerprint: {}", fingerprint));
+    if hex.len() != ring::digest::SHA256_OUTPUT_LEN * 2 {
+        return Err(invalid());
+    }
+
+    hex.chunks(2)
+        .map(|pair| {
+            let hi = pair[0].to_digit(16).ok_or_else(invalid)?;
+            let lo = pair[1].to_digit(16).ok_or_else(invalid)?;
+            Ok((hi * 16 + lo) as u8)
+        })
+        .collect()
+}
+
+/// Verifies a certificate chain against the certificate in it whose SHA-256 fingerprint
+/// matches the expected fingerprint.
+///
+/// The matching certificate is the only trust anchor: the server certificate must chain up
+/// to it, with valid signatures, and be valid for the hostname. When the matching certificate
+/// is the server certificate itself, it is accepted as is.
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+struct FingerprintVerifier {
+    fingerprint: Vec<u8>,
+    webpki: rustls::WebPKIVerifier,
+}
+
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+impl FingerprintVerifier {
+    fn new(fingerprint: Vec<u8>) -> Self {
+        Self {
+            fingerprint,
+            webpki: rustls::WebPKIVerifier::new(),
+        }
+    }
+
+    /// The position in the chain of the certificate that matches the fingerprint
+    fn position(&self, presented_certs: &[rustls::Certificate]) -> Option<usize> {
+        presented_certs.iter().position(|cert| {
+            ring::digest::digest(&ring::digest::SHA256, &cert.0).as_ref()
+                == self.fingerprint.as_slice()
+        })
+    }
+}
+
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+impl rustls::ServerCertVerifier for FingerprintVerifier {
+    fn verify_server_cert(
+        &self,
+        _roots: &rustls::RootCertStore,
+        presented_certs: &[rustls::Certificate],
+        dns_name: webpki::DNSNameRef,
+        ocsp_response: &[u8],
+    ) -> Result<rustls::ServerCertVerified, rustls::TLSError> {
+        match self.position(presented_certs) {
+            Some(0) => Ok(rustls::ServerCertVerified::assertion()),
+            Some(position) => {
+                let mut anchor = rustls::RootCertStore::empty();
+                anchor.add(&presented_certs[position]).map_err(|e| {
+                    rustls::TLSError::General(format!(
+                        "the certificate matching the SHA-256 fingerprint is not a valid CA certificate: {:?}",
+                        e
+                    ))
+                })?;
+                self.webpki.verify_server_cert(
+                    &anchor,
+                    &presented_certs[..position],
+                    dns_name,
+                    ocsp_response,
+                )
+            }
+            None => Err(rustls::TLSError::General(
+                "no certificate in the server certificate chain matches the SHA-256 fingerprint"
+                    .into(),
+            )),
+        }
+    }
+}
+
+/// Accepts any certificate chain
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+struct NoVerifier;
+
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+impl rustls::ServerCertVerifier for NoVerifier {
+    fn verify_server_cert(
+        &self,
+        _roots: &rustls::RootCertStore,
+        _presented_certs: &[rustls::Certificate],
+        _dns_name: webpki::DNSNameRef,
+        _ocsp_response: &[u8],
+    ) -> Result<rustls::ServerCertVerified, rustls::TLSError> {
+        Ok(rustls::ServerCertVerified::assertion())
+    }
+}
+
+/// Builds a rustls TLS configuration, authenticating with the client certificate, if any
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+fn rustls_config(
+    options: &TlsOptions,
+    identity: Option<&Identity>,
+) -> Result<rustls::ClientConfig, BuildError> {
+    let mut tls = rustls::ClientConfig::new();
+    tls.set_protocols(&["h2".into(), "http/1.1".into()]);
+
+    if let Some(fingerprint) = &options.fingerprint {
+        let verifier = FingerprintVerifier::new(parse_fingerprint(fingerprint)?);
+        tls.dangerous().set_certificate_verifier(Arc::new(verifier));
+    } else if options.accept_invalid_certs {
+        tls.dangerous()
+            .set_certificate_verifier(Arc::new(NoVerifier));
+    }
+
+    if !options.disable_built_in_roots {
+        tls.root_store
+            .add_server_trust_anchors(&webpki_roots::TLS_SERVER_ROOTS);
+    }
+
+    for root in &options.roots {
+        tls.root_store
+            .add(&rustls::Certificate(root.der.clone()))
+            .map_err(|e| BuildError::Tls(format!("failed to load certificate: {:?}", e)))?;
+    }
+
+    let identity = match identity {
+        Some(Identity::Pkcs12(der, password)) => Some(rustls_pkcs12_identity(der, password)?),
+        Some(Identity::Pem(certs, key)) => Some((
+            certs.iter().cloned().map(rustls::Certificate).collect(),
+            rustls::PrivateKey(key.clone()),
+        )),
+        None => None,
+    };
+    if let Some((certs, key)) = identity {
+        tls.set_single_client_cert(certs, key)
+            .map_err(|e| BuildError::Tls(format!("failed to load client certificate: {}", e)))?;
+    }
+
+    Ok(tls)
+}
+
+/// Parses a PKCS#12 archive into the certificate chain and private key of the client
+/// certificate, as rustls does not read PKCS#12
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+fn rustls_pkcs12_identity(
+    der: &[u8],
+    password: &str,
+) -> Result<(Vec<rustls::Certificate>, rustls::PrivateKey), BuildError> {
+    let error = |e: &dyn fmt::Display| {
+        BuildError::Tls(format!("failed to parse PKCS#12 client certificate: {}", e))
+    };
+    let keystore = p12_keystore::KeyStore::from_pkcs12(der, password).map_err(|e| error(&e))?;
+    let (_, chain) = keystore
+        .private_key_chain()
+        .ok_or_else(|| error(&"no private key found"))?;
+    let certs = chain
+        .chain()
+        .iter()
+        .map(|cert| rustls::Certificate(cert.as_der().to_vec()))
+        .collect();
+    Ok((certs, rustls::PrivateKey(chain.key().to_vec())))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::cert::*;
+
+    static CA_CERT: &[u8] = include_bytes!("../../.ci/certs/ca.crt");
+    static TESTNODE_CERT: &[u8] = include_bytes!("../../.ci/certs/testnode.crt");
+    static TESTNODE_KEY: &[u8] = include_bytes!("../../.ci/certs/testnode.key");
+    static TESTNODE_P12: &[u8] = include_bytes!("../../.ci/certs/testnode.p12");
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    static TESTNODE_SAN_CERT: &[u8] = include_bytes!("../../.ci/certs/testnode_san.crt");
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    static OTHER_CA_CERT: &[u8] = include_bytes!("../../.ci/certs/ca.pem");
+
+    #[test]
+    fn certificate_from_pem() {
+        let cert = Certificate::from_pem(CA_CERT).unwrap();
+        assert_eq!(Certificate::from_der(&cert.der).unwrap().der, cert.der);
+    }
+
+    #[test]
+    fn certificates_from_pem_bundle() {
+        let pem = [CA_CERT, TESTNODE_CERT].concat();
+        let bundle = Certificate::from_pem_bundle(&pem).unwrap();
+        assert_eq!(bundle.len(), 2);
+        assert_eq!(bundle[0].der, Certificate::from_pem(CA_CERT).unwrap().der);
+        assert_eq!(
+            bundle[1].der,
+            Certificate::from_pem(TESTNODE_CERT).unwrap().der
+        );
+
+        // only the first certificate of a bundle
+        assert_eq!(Certificate::from_pem(&pem).unwrap().der, bundle[0].der);
+    }
+
+    #[test]
+    fn certificate_from_invalid_pem() {
+        let err = Certificate::from_pem(b"Nonsense").unwrap_err();
+        assert!(err.to_string().contains("no PEM encoded certificate found"));
+    }
+
+    #[test]
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    fn pem_identity_wraps_pkcs1_private_key() {
+        for pem in vec![
+            [TESTNODE_CERT, TESTNODE_KEY].concat(),
+            [TESTNODE_KEY, TESTNODE_CERT].concat(),
+        ] {
+            match Identity::pem(&pem, &pem).unwrap() {
+                Identity::Pem(certs, key) => {
+                    assert_eq!(certs.len(), 1);
+                    assert!(ring::signature::RsaKeyPair::from_pkcs8(&key).is_ok());
+                }
+                _ => panic!("expected PEM identity"),
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    fn client_certificate_errors_name_the_part() {
+        let configure = |cert: &[u8], key: &[u8]| {
+            let cert = ClientCertificate::PemWithKey(cert.to_vec(), key.to_vec());
+            configure_tls(
+                reqwest::ClientBuilder::new(),
+                CertificateValidation::Default,
+                true,
+                Some(&cert),
+            )
+            .err()
+            .unwrap()
+            .to_string()
+        };
+
+        assert!(
+            configure(b"Nonsense", TESTNODE_KEY).starts_with("failed to parse client certificate:")
+        );
+        assert!(configure(TESTNODE_CERT, TESTNODE_CERT)
+            .starts_with("failed to parse client certificate private key:"));
+    }
+
+    #[test]
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    fn configure_tls_with_client_certificate() {
+        for cert in vec![
+            ClientCertificate::Pem([TESTNODE_CERT, TESTNODE_KEY].concat()),
+            ClientCertificate::PemWithKey(TESTNODE_CERT.to_vec(), TESTNODE_KEY.to_vec()),
+            ClientCertificate::Pkcs12(TESTNODE_P12.to_vec(), Some("changeme".into())),
+        ] {
+            let validation = CertificateValidation::Full(Certificate::from_pem(CA_CERT).unwrap());
+            let builder =
+                configure_tls(reqwest::ClientBuilder::new(), validation, true, Some(&cert))
+                    .unwrap();
+            assert!(builder.build().is_ok());
+        }
+
+        let cert = ClientCertificate::Pkcs12(TESTNODE_P12.to_vec(), Some("wrong".into()));
+        let validation = CertificateValidation::Full(Certificate::from_pem(CA_CERT).unwrap());
+        assert!(
+            configure_tls(reqwest::ClientBuilder::new(), validation, true, Some(&cert)).is_err()
+        );
+    }
+
+    #[test]
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    fn rustls_pkcs12_identity_has_certificate_chain_and_key() {
+        let (certs, key) = rustls_pkcs12_identity(TESTNODE_P12, "changeme").unwrap();
+        assert_eq!(certs.len(), 1);
+        assert_eq!(certs[0].0, pem_certificates(TESTNODE_CERT).unwrap()[0]);
+        assert!(ring::signature::RsaKeyPair::from_pkcs8(&key.0).is_ok());
+
+        assert!(rustls_pkcs12_identity(TESTNODE_P12, "wrong").is_err());
+        assert!(rustls_pkcs12_identity(b"Nonsense", "changeme").is_err());
+    }
+
+    #[test]
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    fn configure_tls_without_built_in_root_certs() {
+        let bundle = Certificate::from_pem_bundle(&[CA_CERT, TESTNODE_CERT].concat()).unwrap();
+        let builder = configure_tls(
+            reqwest::ClientBuilder::new(),
+            CertificateValidation::FullBundle(bundle),
+            false,
+            None,
+        )
+        .unwrap();
+        assert!(builder.build().is_ok());
+    }
+
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    fn fingerprint(cert: &[u8]) -> String {
+        ring::digest::digest(&ring::digest::SHA256, cert)
+            .as_ref()
+            .iter()
+            .map(|b| format!("{:02X}", b))
+            .collect::<Vec<_>>()
+            .join(":")
+    }
+
+    #[test]
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    fn parse_fingerprint_ignores_separators_and_case() {
+        let colons = parse_fingerprint(&fingerprint(CA_CERT)).unwrap();
+        let plain =
+            parse_fingerprint(&fingerprint(CA_CERT).replace(":", "").to_lowercase()).unwrap();
+        assert_eq!(colons.len(), 32);
+        assert_eq!(colons, plain);
+    }
+
+    #[test]
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    fn parse_fingerprint_rejects_invalid() {
+        assert!(parse_fingerprint("A5:21:F2").is_err());
+        assert!(parse_fingerprint(&fingerprint(CA_CERT).replace("A", "Z")).is_err());
+        let options = TlsOptions {
+            fingerprint: Some("not a fingerprint".into()),
+            ..Default::default()
+        };
+        assert!(rustls_config(&options, None).is_err());
+    }
+
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    fn verify_with_fingerprint(
+        pinned: &[u8],
+        chain: &[&[u8]],
+        dns_name: &str,
+    ) -> Result<rustls::ServerCertVerified, rustls::TLSError> {
+        use rustls::ServerCertVerifier;
+
+        let der = |pem: &[u8]| pem_certificates(pem).unwrap().remove(0);
+        let mut verifier =
+            FingerprintVerifier::new(parse_fingerprint(&fingerprint(&der(pinned))).unwrap());
+        // the test certificates expired in 2022
+        verifier.webpki.time = || Ok(webpki::Time::from_seconds_since_unix_epoch(1_620_000_000));
+        let chain: Vec<rustls::Certificate> = chain
+            .iter()
+            .map(|pem| rustls::Certificate(der(pem)))
+            .collect();
+        verifier.verify_server_cert(
+            &rustls::RootCertStore::empty(),
+            &chain,
+            webpki::DNSNameRef::try_from_ascii_str(dns_name).unwrap(),
+            &[],
+        )
+    }
+
+    #[test]
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    fn verifier_verifies_chain_up_to_pinned_certificate() {
+        assert!(
+            verify_with_fingerprint(CA_CERT, &[TESTNODE_SAN_CERT, CA_CERT], "localhost").is_ok()
+        );
+        assert!(
+            verify_with_fingerprint(CA_CERT, &[TESTNODE_SAN_CERT, CA_CERT], "example.com").is_err()
+        );
+        assert!(verify_with_fingerprint(CA_CERT, &[TESTNODE_SAN_CERT], "localhost").is_err());
+        // a certificate not signed by the pinned certificate, with it appended to the chain
+        assert!(verify_with_fingerprint(CA_CERT, &[OTHER_CA_CERT, CA_CERT], "localhost").is_err());
+    }
+
+    #[test]
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    fn verifier_accepts_pinned_server_certificate() {
+        assert!(
+            verify_with_fingerprint(TESTNODE_SAN_CERT, &[TESTNODE_SAN_CERT], "example.com").is_ok()
+        );
+    }
+
+    #[test]
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    fn configure_tls_with_fingerprint() {
+        let validation = CertificateValidation::Fingerprint(fingerprint(CA_CERT));
+        let builder = configure_tls(reqwest::ClientBuilder::new(), validation, true, None).unwrap();
+        assert!(builder.build().is_ok());
+    }
+
+    #[test]
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    fn fingerprint_with_client_certificate() {
+        let options = TlsOptions {
+            fingerprint: Some(fingerprint(CA_CERT)),
+            ..Default::default()
+        };
+        for cert in vec![
+            ClientCertificate::PemWithKey(TESTNODE_CERT.to_vec(), TESTNODE_KEY.to_vec()),
+            ClientCertificate::Pkcs12(TESTNODE_P12.to_vec(), Some("changeme".into())),
+        ] {
+            let identity = Identity::parse(&cert).unwrap();
+            assert!(rustls_config(&options, Some(&identity)).is_ok());
+        }
+    }
+}
@@ -0,0 +1,547 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! Client configuration components
+//!
+//! A [ClientConfig] describes how a client connects to Elasticsearch, and can be
+//! deserialized with [serde](https://serde.rs/) from any format that serde supports, such as
+//! TOML, YAML or JSON, allowing connection settings to be changed without code changes.
+//! A transport is built from a configuration with
+//! [TransportBuilder::from_config](crate::http::transport::TransportBuilder::from_config),
+//! which returns a builder that can be configured further.
+//!
+//! Periods of time are given in [Elasticsearch time units](https://www.elastic.co/guide/en/elasticsearch/reference/current/common-options.html#time-units),
+//! such as `"500ms"` or `"30s"`.
+//!
+//! ```rust,no_run
+//! # use elasticsearch::{
+//! #     config::ClientConfig,
+//! #     http::transport::TransportBuilder,
+//! #     Elasticsearch,
+//! # };
+//! # fn doc() -> Result<(), Box<dyn std::error::Error>> {
+//! let config: ClientConfig = serde_json::from_str(
+//!     r#"{
+//!         "nodes": ["https://es1.example.com:9200", "https://es2.example.com:9200"],
+//!         "auth": { "type": "api_key", "id": "<id>", "api_key": "<api_key>" },
+//!         "tls": { "ca_certs": ["/etc/elasticsearch/certs/ca.crt"] },
+//!         "proxy": { "url": "http://proxy.example.com:3128", "no_proxy": [".internal"] },
+//!         "headers": { "x-opaque-id": "my-service" },
+//!         "timeout": "30s",
+//!         "connect_timeout": "5s"
+//!     }"#,
+//! )?;
+//!
+//! let transport = TransportBuilder::from_config(&config)?.build()?;
+//! let client = Elasticsearch::new(transport);
+//! # Ok(())
+//! # }
+//! ```
+
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+use crate::{auth::ClientCertificate, cert::Certificate};
+use crate::{auth::Credentials, cert::CertificateValidation, error::Error};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, path::PathBuf, time::Duration};
+
+/// The configuration of a client, from which a transport is built with
+/// [TransportBuilder::from_config](crate::http::transport::TransportBuilder::from_config).
+///
+/// All fields are optional, and a default configuration connects to
+/// [DEFAULT_ADDRESS](crate::http::transport::DEFAULT_ADDRESS).
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ClientConfig {
+    /// The urls of the nodes to connect to. A single url uses a
+    /// [SingleNodeConnectionPool](crate::http::transport::SingleNodeConnectionPool), and several
+    /// urls use a [StaticNodeListConnectionPool](crate::http::transport::StaticNodeListConnectionPool).
+    /// A username and password in the urls are used for Basic authentication when
+    /// [ClientConfig::auth] is not set.
+    pub nodes: Vec<String>,
+    /// The Cloud Id of a deployment in Elastic Cloud, used with a
+    /// [CloudConnectionPool](crate::http::transport::CloudConnectionPool). Cannot be set
+    /// together with [ClientConfig::nodes].
+    pub cloud_id: Option<String>,
+    /// Credentials with which to authenticate to Elasticsearch
+    pub auth: Option<AuthConfig>,
+    /// Validation of the certificates provided by nodes
+    pub tls: TlsConfig,
+    /// Proxy through which to send requests
+    pub proxy: Option<ProxyConfig>,
+    /// Headers to add to all API calls
+    pub headers: BTreeMap<String, String>,
+    /// Timeout for API calls
+    #[serde(with = "time_units")]
+    pub timeout: Option<Duration>,
+    /// Timeout for connecting to nodes
+    #[serde(with = "time_units")]
+    pub connect_timeout: Option<Duration>,
+}
+
+/// Credentials with which to authenticate to Elasticsearch, distinguished by a `type` field
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum AuthConfig {
+    /// A username and password for Basic authentication
+    Basic {
+        /// The username
+        username: String,
+        /// The password
+        password: String,
+    },
+    /// An API key for API key authentication
+    ApiKey {
+        /// The id of the API key. When not set, the API key is given as `id:api_key`
+        /// or base64 encoded, as returned in the `encoded` field of the create API key API.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        /// The API key
+        api_key: String,
+    },
+    /// An access token for Bearer authentication
+    Bearer {
+        /// The access token
+        token: String,
+    },
+    /// A PEM encoded client certificate for PKI authentication.
+    ///
+    /// Requires the `native-tls` or `rustls-tls` feature to be enabled.
+    Certificate {
+        /// The path to a PEM file of the client certificate and any intermediate certificates,
+        /// which also contains the private key when `key` is not set
+        cert: PathBuf,
+        /// The path to a PEM file of the private key
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        key: Option<PathBuf>,
+    },
+    /// A PKCS#12 archive of a client certificate for PKI authentication.
+    ///
+    /// Requires the `native-tls` or `rustls-tls` feature to be enabled.
+    Pkcs12 {
+        /// The path to the PKCS#12 archive
+        path: PathBuf,
+        /// The passphrase of the archive
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        password: Option<String>,
+    },
+}
+
+impl AuthConfig {
+    /// Creates the [Credentials] for the configuration, reading any files
+    pub(crate) fn credentials(&self) -> Result<Credentials, Error> {
+        match self {
+            AuthConfig::Basic { username, password } => {
+                Ok(Credentials::Basic(username.clone(), password.clone()))
+            }
+            AuthConfig::ApiKey {
+                id: Some(id),
+                api_key,
+            } => Ok(Credentials::ApiKey(id.clone(), api_key.clone())),
+            AuthConfig::ApiKey { id: None, api_key } => {
+                crate::http::transport::parse_api_key(api_key)
+            }
+            AuthConfig::Bearer { token } => Ok(Credentials::Bearer(token.clone())),
+            #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+            AuthConfig::Certificate { cert, key } => Ok(Credentials::Certificate(match key {
+                Some(key) => ClientCertificate::from_pem_files(cert, key)?,
+                None => ClientCertificate::Pem(std::fs::read(cert)?),
+            })),
+            #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+            AuthConfig::Pkcs12 { path, password } => Ok(Credentials::Certificate(
+                ClientCertificate::Pkcs12(std::fs::read(path)?, password.clone()),
+            )),
+            #[cfg(not(any(feature = "native-tls", feature = "rustls-tls")))]
+            AuthConfig::Certificate { .. } | AuthConfig::Pkcs12 { .. } => Err(Error::lib(
+                "certificate authentication requires the native-tls or rustls-tls feature",
+            )),
+        }
+    }
+}
+
+/// Validation of the certificates provided by nodes to establish HTTPS connections
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TlsConfig {
+    /// How certificates are validated. When not set, [ValidationMode::Fingerprint] is used
+    /// if [TlsConfig::fingerprint] is set, [ValidationMode::Full] if [TlsConfig::ca_certs]
+    /// are set, and [ValidationMode::Default] otherwise.
+    pub validation: Option<ValidationMode>,
+    /// Paths to PEM files of Certificate Authority (CA) certificates, for
+    /// [ValidationMode::Full] and [ValidationMode::Certificate]
+    pub ca_certs: Vec<PathBuf>,
+    /// The SHA-256 fingerprint of a certificate, for [ValidationMode::Fingerprint]
+    pub fingerprint: Option<String>,
+    /// Whether to trust the root certificates of the operating system. Defaults to `true`.
+    pub built_in_root_certs: bool,
+}
+
+impl Default for TlsConfig {
+    fn default() -> Self {
+        Self {
+            validation: None,
+            ca_certs: Vec::new(),
+            fingerprint: None,
+            built_in_root_certs: true,
+        }
+    }
+}
+
+/// How certificates provided by nodes are validated, corresponding to a [CertificateValidation]
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationMode {
+    /// [CertificateValidation::Default]
+    Default,
+    /// [CertificateValidation::FullBundle] with [TlsConfig::ca_certs]. Requires the `native-tls` or
+    /// `rustls-tls` feature to be enabled.
+    Full,
+    /// `CertificateValidation::CertificateBundle` with [TlsConfig::ca_certs]. Requires the
+    /// `native-tls` feature to be enabled.
+    Certificate,
+    /// `CertificateValidation::Fingerprint` with [TlsConfig::fingerprint]. Requires the
+    /// `native-tls` or `rustls-tls` feature to be enabled.
+    Fingerprint,
+    /// [CertificateValidation::None]
+    None,
+}
+
+impl TlsConfig {
+    /// Creates the [CertificateValidation] for the configuration, reading any files, or `None`
+    /// when the default validation is used
+    pub(crate) fn validation(&self) -> Result<Option<CertificateValidation>, Error> {
+        let mode = match self.validation {
+            Some(mode) => mode,
+            None if self.fingerprint.is_some() => ValidationMode::Fingerprint,
+            None if !self.ca_certs.is_empty() => ValidationMode::Full,
+            None => return Ok(None),
+        };
+
+        if !self.ca_certs.is_empty()
+            && !matches!(mode, ValidationMode::Full | ValidationMode::Certificate)
+        {
+            return Err(Error::lib(
+                "ca_certs can only be set for full or certificate validation",
+            ));
+        }
+        if self.fingerprint.is_some() && mode != ValidationMode::Fingerprint {
+            return Err(Error::lib(
+                "fingerprint can only be set for fingerprint validation",
+            ));
+        }
+
+        match mode {
+            ValidationMode::Default => Ok(Some(CertificateValidation::Default)),
+            #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+            ValidationMode::Full => Ok(Some(CertificateValidation::FullBundle(self.ca()?))),
+            #[cfg(feature = "native-tls")]
+            ValidationMode::Certificate => {
+                Ok(Some(CertificateValidation::CertificateBundle(self.ca()?)))
+            }
+            #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+            ValidationMode::Fingerprint => match &self.fingerprint {
+                Some(fingerprint) => Ok(Some(CertificateValidation::Fingerprint(
+                    fingerprint.clone(),
+                ))),
+                None => Err(Error::lib("fingerprint validation requires a fingerprint")),
+            },
+            ValidationMode::None => Ok(Some(CertificateValidation::None)),
+            #[allow(unreachable_patterns)]
+            mode => Err(Error::lib(format!(
+                "{:?} validation is not supported by the enabled TLS features",
+                mode
+            ))),
+        }
+    }
+
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    fn ca(&self) -> Result<Vec<Certificate>, Error> {
+        if self.ca_certs.is_empty() {
+            return Err(Error::lib(
+                "full and certificate validation require ca_certs",
+            ));
+        }
+        let mut ca = Vec::new();
+        for path in &self.ca_certs {
+            ca.extend(Certificate::from_pem_bundle_file(path)?);
+        }
+        Ok(ca)
+    }
+}
+
+/// A proxy through which to send requests
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProxyConfig {
+    /// The url of a proxy for all requests
+    pub url: Option<String>,
+    /// The url of a proxy for requests to nodes addressed over http, taking precedence
+    /// over [ProxyConfig::url]
+    pub http_url: Option<String>,
+    /// The url of a proxy for requests to nodes addressed over https, taking precedence
+    /// over [ProxyConfig::url]
+    pub https_url: Option<String>,
+    /// Hosts to which requests are sent directly, rather than through the proxy
+    pub no_proxy: Vec<String>,
+    /// The username for Basic authentication to the proxy
+    pub username: Option<String>,
+    /// The password for Basic authentication to the proxy
+    pub password: Option<String>,
+    /// Credentials with which to authenticate to the proxy, taking precedence over
+    /// [ProxyConfig::username] and [ProxyConfig::password]. Credentials other than Basic
+    /// credentials are subject to the limitations of
+    /// [TransportBuilder::proxy_auth](crate::http::transport::TransportBuilder::proxy_auth).
+    pub auth: Option<AuthConfig>,
+    /// A raw value for the `Proxy-Authorization` header, taking precedence over
+    /// [ProxyConfig::auth], as with
+    /// [TransportBuilder::proxy_authorization](crate::http::transport::TransportBuilder::proxy_authorization)
+    pub authorization: Option<String>,
+    /// Whether to disable proxies, including system proxies
+    pub disabled: bool,
+}
+
+/// (De)serializes an optional [Duration] as an Elasticsearch time unit string, such as `30s`
+mod time_units {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    const UNITS: [(&str, u128); 7] = [
+        ("d", 86_400_000_000_000),
+        ("h", 3_600_000_000_000),
+        ("m", 60_000_000_000),
+        ("s", 1_000_000_000),
+        ("ms", 1_000_000),
+        ("micros", 1_000),
+        ("nanos", 1),
+    ];
+
+    pub fn serialize<S>(value: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match value {
+            Some(d) => {
+                let nanos = d.as_nanos();
+                let (unit, size) = UNITS
+                    .iter()
+                    .find(|(_, size)| nanos % size == 0)
+                    .unwrap_or(&UNITS[UNITS.len() - 1]);
+                serializer.serialize_some(&format!("{}{}", nanos / size, unit))
+            }
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(s) => parse(&s).map(Some).map_err(de::Error::custom),
+            None => Ok(None),
+        }
+    }
+
+    pub(super) fn parse(s: &str) -> Result<Duration, String> {
+        let s = s.trim();
+        let split = s
+            .find(|c: char| !c.is_ascii_digit())
+            .ok_or_else(|| format!("missing time unit in '{}'", s))?;
+        let (amount, unit) = s.split_at(split);
+        let amount: u64 = amount
+            .parse()
+            .map_err(|_| format!("invalid time value '{}'", s))?;
+        let size = UNITS
+            .iter()
+            .find(|(u, _)| *u == unit)
+            .map(|(_, size)| *size as u64)
+            .ok_or_else(|| format!("invalid time unit in '{}'", s))?;
+        amount
+            .checked_mul(size)
+            .map(Duration::from_nanos)
+            .ok_or_else(|| format!("time value '{}' is too large", s))
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::config::*;
+    use serde_json::json;
+
+    #[test]
+    fn deserialize_client_config() {
+        let config: ClientConfig = serde_json::from_value(json!({
+            "nodes": ["http://es1:9200", "http://es2:9200"],
+            "auth": { "type": "basic", "username": "elastic", "password": "changeme" },
+            "tls": { "validation": "none", "built_in_root_certs": false },
+            "proxy": { "url": "http://proxy:3128", "no_proxy": [".internal"] },
+            "headers": { "x-opaque-id": "my-service" },
+            "timeout": "30s",
+            "connect_timeout": "500ms"
+        }))
+        .unwrap();
+
+        assert_eq!(config.nodes, vec!["http://es1:9200", "http://es2:9200"]);
+        assert_eq!(
+            config.auth,
+            Some(AuthConfig::Basic {
+                username: "elastic".into(),
+                password: "changeme".into()
+            })
+        );
+        assert_eq!(config.tls.validation, Some(ValidationMode::None));
+        assert!(!config.tls.built_in_root_certs);
+        assert_eq!(
+            config.proxy.unwrap().url,
+            Some("http://proxy:3128".to_string())
+        );
+        assert_eq!(config.headers["x-opaque-id"], "my-service");
+        assert_eq!(config.timeout, Some(Duration::from_secs(30)));
+        assert_eq!(config.connect_timeout, Some(Duration::from_millis(500)));
+    }
+
+    #[test]
+    fn deserialize_empty_client_config() {
+        let config: ClientConfig = serde_json::from_value(json!({})).unwrap();
+
+        assert_eq!(config, ClientConfig::default());
+        assert!(config.tls.built_in_root_certs);
+    }
+
+    #[test]
+    fn deserialize_unknown_field_is_error() {
+        let res = serde_json::from_value::<ClientConfig>(json!({ "node": "http://es1:9200" }));
+        assert!(res.is_err());
+
+        let res = serde_json::from_value::<ClientConfig>(json!({
+            "auth": { "type": "basic", "username": "elastic", "pasword": "changeme" }
+        }));
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn serialize_client_config_roundtrip() {
+        let config = ClientConfig {
+            cloud_id: Some("my_cluster:abc".into()),
+            auth: Some(AuthConfig::ApiKey {
+                id: None,
+                api_key: "id:api_key".into(),
+            }),
+            timeout: Some(Duration::from_secs(90)),
+            connect_timeout: Some(Duration::from_micros(1500)),
+            ..Default::default()
+        };
+
+        let value = serde_json::to_value(&config).unwrap();
+        assert_eq!(value["timeout"], "90s");
+        assert_eq!(value["connect_timeout"], "1500micros");
+        assert_eq!(
+            value["auth"],
+            json!({ "type": "api_key", "api_key": "id:api_key" })
+        );
+
+        let roundtrip: ClientConfig = serde_json::from_value(value).unwrap();
+        assert_eq!(roundtrip, config);
+    }
+
+    #[test]
+    fn parse_time_units() {
+        assert_eq!(time_units::parse("2d"), Ok(Duration::from_secs(172_800)));
+        assert_eq!(time_units::parse("1h"), Ok(Duration::from_secs(3600)));
+        assert_eq!(time_units::parse("5m"), Ok(Duration::from_secs(300)));
+        assert_eq!(time_units::parse(" 10s "), Ok(Duration::from_secs(10)));
+        assert_eq!(time_units::parse("250ms"), Ok(Duration::from_millis(250)));
+        assert_eq!(time_units::parse("7micros"), Ok(Duration::from_micros(7)));
+        assert_eq!(time_units::parse("3nanos"), Ok(Duration::from_nanos(3)));
+
+        for invalid in &["", "10", "s", "10 s", "-1s", "1.5s", "10sec"] {
+            assert!(time_units::parse(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn api_key_credentials() {
+        let auth = AuthConfig::ApiKey {
+            id: Some("id".into()),
+            api_key: "api_key".into(),
+        };
+        assert!(
+            matches!(auth.credentials(), Ok(Credentials::ApiKey(i, k)) if i == "id" && k == "api_key")
+        );
+
+        let auth = AuthConfig::ApiKey {
+            id: None,
+            api_key: base64::encode("id:api_key"),
+        };
+        assert!(
+            matches!(auth.credentials(), Ok(Credentials::ApiKey(i, k)) if i == "id" && k == "api_key")
+        );
+    }
+
+    #[test]
+    fn default_tls_config_has_no_validation() {
+        assert!(matches!(TlsConfig::default().validation(), Ok(None)));
+    }
+
+    #[test]
+    fn mismatched_tls_config_is_error() {
+        let tls = TlsConfig {
+            validation: Some(ValidationMode::None),
+            ca_certs: vec!["ca.crt".into()],
+            ..Default::default()
+        };
+        assert!(tls.validation().is_err());
+
+        let tls = TlsConfig {
+            validation: Some(ValidationMode::Full),
+            fingerprint: Some("00".into()),
+            ..Default::default()
+        };
+        assert!(tls.validation().is_err());
+    }
+
+    #[test]
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    fn ca_certs_infer_full_validation() {
+        let tls = TlsConfig {
+            ca_certs: vec![
+                concat!(env!("CARGO_MANIFEST_DIR"), "/../.ci/certs/ca.crt").into(),
+                concat!(env!("CARGO_MANIFEST_DIR"), "/../.ci/certs/testnode.crt").into(),
+            ],
+            ..Default::default()
+        };
+
+        match tls.validation() {
+            Ok(Some(CertificateValidation::FullBundle(ca))) => assert_eq!(ca.len(), 2),
+            _ => panic!("expected full validation"),
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    fn fingerprint_infers_fingerprint_validation() {
+        let tls = TlsConfig {
+            fingerprint: Some("00:11".into()),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            tls.validation(),
+            Ok(Some(CertificateValidation::Fingerprint(f))) if f == "00:11"
+        ));
+    }
+}
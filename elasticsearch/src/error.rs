@@ -32,7 +32,7 @@
  * See the License for the specific language governing permissions and
  * limitations under the License.
  */
-use crate::http::transport::BuildError;
+use crate::http::{client::HttpError, transport::BuildError, StatusCode, Url};
 use std::error;
 use std::fmt;
 use std::io;
@@ -55,7 +55,14 @@ enum Kind {
     Lib(String),
 
     /// HTTP library error
-    Http(reqwest::Error),
+    Http(HttpError),
+
+    /// The API call did not complete within the request timeout, or a connection
+    /// could not be established within the connect timeout
+    Timeout(HttpError),
+
+    /// A response from Elasticsearch with an error status code
+    Status(StatusCode, Url),
 
     /// IO error
     Io(io::Error),
@@ -72,11 +79,14 @@ impl From<io::Error> for Error {
     }
 }
 
-impl From<reqwest::Error> for Error {
-    fn from(err: reqwest::Error) -> Error {
-        Error {
-            kind: Kind::Http(err),
-        }
+impl From<HttpError> for Error {
+    fn from(err: HttpError) -> Error {
+        let kind = if err.is_timeout() {
+            Kind::Timeout(err)
+        } else {
+            Kind::Http(err)
+        };
+        Error { kind }
     }
 }
 
@@ -110,6 +120,36 @@ impl Error {
             kind: Kind::Lib(err.into()),
         }
     }
+
+    pub(crate) fn status(status: StatusCode, url: Url) -> Self {
+        Error {
+            kind: Kind::Status(status, url),
+        }
+    }
+
+    /// The status code of the response, if the error was created from a response
+    /// with an error status code
+    pub fn status_code(&self) -> Option<StatusCode> {
+        match &self.kind {
+            Kind::Status(status, _) => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Whether the error occurred connecting to a node
+    pub(crate) fn is_connection(&self) -> bool {
+        match &self.kind {
+            Kind::Http(err) | Kind::Timeout(err) => err.is_connect(),
+            _ => false,
+        }
+    }
+
+    /// Whether the error is a timeout, because the API call did not complete within
+    /// the request timeout, or a connection could not be established within the
+    /// connect timeout
+    pub fn is_timeout(&self) -> bool {
+        matches!(self.kind, Kind::Timeout(_))
+    }
 }
 
 impl error::Error for Error {
@@ -118,6 +158,8 @@ impl error::Error for Error {
             Kind::Build(err) => Some(err),
             Kind::Lib(_) => None,
             Kind::Http(err) => Some(err),
+            Kind::Timeout(err) => Some(err),
+            Kind::Status(_, _) => None,
             Kind::Io(err) => Some(err),
             Kind::Json(err) => Some(err),
         }
@@ -130,6 +172,15 @@ impl fmt::Display for Error {
             Kind::Build(err) => err.fmt(f),
             Kind::Lib(err) => err.fmt(f),
             Kind::Http(err) => err.fmt(f),
+            Kind::Timeout(err) => err.fmt(f),
+            Kind::Status(status, url) => {
+                let prefix = if status.is_client_error() {
+                    "HTTP status client error"
+                } else {
+                    "HTTP status server error"
+                };
+                write!(f, "{} ({}) for url ({})", prefix, status, url)
+            }
             Kind::Io(err) => err.fmt(f),
             Kind::Json(err) => err.fmt(f),
         }
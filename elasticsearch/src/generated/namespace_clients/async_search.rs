@@ -37,6 +37,7 @@ use crate::{
 use percent_encoding::percent_encode;
 use serde::Serialize;
 use std::borrow::Cow;
+use std::time::Duration;
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Async Search Delete API"]
 pub enum AsyncSearchDeleteParts<'b> {
@@ -67,6 +68,7 @@ pub struct AsyncSearchDelete<'a, 'b> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
 }
 impl<'a, 'b> AsyncSearchDelete<'a, 'b> {
@@ -81,6 +83,7 @@ impl<'a, 'b> AsyncSearchDelete<'a, 'b> {
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             source: None,
         }
     }
@@ -109,6 +112,11 @@ impl<'a, 'b> AsyncSearchDelete<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -119,6 +127,7 @@ impl<'a, 'b> AsyncSearchDelete<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Delete;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -149,10 +158,16 @@ impl<'a, 'b> AsyncSearchDelete<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("async_search.delete")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Async Search Delete API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Async Search Get API"]
@@ -185,6 +200,7 @@ pub struct AsyncSearchGet<'a, 'b> {
     human: Option<bool>,
     keep_alive: Option<&'b str>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
     typed_keys: Option<bool>,
     wait_for_completion_timeout: Option<&'b str>,
@@ -202,6 +218,7 @@ impl<'a, 'b> AsyncSearchGet<'a, 'b> {
             human: None,
             keep_alive: None,
             pretty: None,
+            request_timeout: None,
             source: None,
             typed_keys: None,
             wait_for_completion_timeout: None,
@@ -237,6 +254,11 @@ impl<'a, 'b> AsyncSearchGet<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -257,6 +279,7 @@ impl<'a, 'b> AsyncSearchGet<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -296,10 +319,16 @@ impl<'a, 'b> AsyncSearchGet<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("async_search.get")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Async Search Get API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Async Search Submit API"]
@@ -361,6 +390,7 @@ pub struct AsyncSearchSubmit<'a, 'b, B> {
     pretty: Option<bool>,
     q: Option<&'b str>,
     request_cache: Option<bool>,
+    request_timeout: Option<Duration>,
     routing: Option<&'b [&'b str]>,
     search_type: Option<SearchType>,
     seq_no_primary_term: Option<bool>,
@@ -420,6 +450,7 @@ where
             pretty: None,
             q: None,
             request_cache: None,
+            request_timeout: None,
             routing: None,
             search_type: None,
             seq_no_primary_term: None,
@@ -518,6 +549,7 @@ where
             pretty: self.pretty,
             q: self.q,
             request_cache: self.request_cache,
+            request_timeout: self.request_timeout,
             routing: self.routing,
             search_type: self.search_type,
             seq_no_primary_term: self.seq_no_primary_term,
@@ -639,6 +671,11 @@ where
         self.request_cache = Some(request_cache);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "A comma-separated list of specific routing values"]
     pub fn routing(mut self, routing: &'b [&'b str]) -> Self {
         self.routing = Some(routing);
@@ -739,6 +776,7 @@ where
         let path = self.parts.url();
         let method = Method::Post;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -910,10 +948,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("async_search.submit")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Async Search Submit API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[doc = "Namespace client for AsyncSearch APIs"]
 pub struct AsyncSearch<'a> {
@@ -37,6 +37,7 @@ use crate::{
 use percent_encoding::percent_encode;
 use serde::Serialize;
 use std::borrow::Cow;
+use std::time::Duration;
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cat Aliases API"]
 pub enum CatAliasesParts<'b> {
@@ -77,6 +78,7 @@ pub struct CatAliases<'a, 'b> {
     human: Option<bool>,
     local: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
     source: Option<&'b str>,
     v: Option<bool>,
@@ -100,6 +102,7 @@ impl<'a, 'b> CatAliases<'a, 'b> {
             human: None,
             local: None,
             pretty: None,
+            request_timeout: None,
             s: None,
             source: None,
             v: None,
@@ -155,6 +158,11 @@ impl<'a, 'b> CatAliases<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Comma-separated list of column names or column aliases to sort by"]
     pub fn s(mut self, s: &'b [&'b str]) -> Self {
         self.s = Some(s);
@@ -175,6 +183,7 @@ impl<'a, 'b> CatAliases<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -226,10 +235,16 @@ impl<'a, 'b> CatAliases<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cat.aliases")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cat Aliases API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cat Allocation API"]
@@ -272,6 +287,7 @@ pub struct CatAllocation<'a, 'b> {
     local: Option<bool>,
     master_timeout: Option<&'b str>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
     source: Option<&'b str>,
     v: Option<bool>,
@@ -296,6 +312,7 @@ impl<'a, 'b> CatAllocation<'a, 'b> {
             local: None,
             master_timeout: None,
             pretty: None,
+            request_timeout: None,
             s: None,
             source: None,
             v: None,
@@ -356,6 +373,11 @@ impl<'a, 'b> CatAllocation<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Comma-separated list of column names or column aliases to sort by"]
     pub fn s(mut self, s: &'b [&'b str]) -> Self {
         self.s = Some(s);
@@ -376,6 +398,7 @@ impl<'a, 'b> CatAllocation<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -430,10 +453,16 @@ impl<'a, 'b> CatAllocation<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cat.allocation")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cat Allocation API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cat Count API"]
@@ -473,6 +502,7 @@ pub struct CatCount<'a, 'b> {
     help: Option<bool>,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
     source: Option<&'b str>,
     v: Option<bool>,
@@ -494,6 +524,7 @@ impl<'a, 'b> CatCount<'a, 'b> {
             help: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             s: None,
             source: None,
             v: None,
@@ -539,6 +570,11 @@ impl<'a, 'b> CatCount<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Comma-separated list of column names or column aliases to sort by"]
     pub fn s(mut self, s: &'b [&'b str]) -> Self {
         self.s = Some(s);
@@ -559,6 +595,7 @@ impl<'a, 'b> CatCount<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -604,10 +641,16 @@ impl<'a, 'b> CatCount<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cat.count")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cat Count API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cat Fielddata API"]
@@ -649,6 +692,7 @@ pub struct CatFielddata<'a, 'b> {
     help: Option<bool>,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
     source: Option<&'b str>,
     v: Option<bool>,
@@ -672,6 +716,7 @@ impl<'a, 'b> CatFielddata<'a, 'b> {
             help: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             s: None,
             source: None,
             v: None,
@@ -727,6 +772,11 @@ impl<'a, 'b> CatFielddata<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Comma-separated list of column names or column aliases to sort by"]
     pub fn s(mut self, s: &'b [&'b str]) -> Self {
         self.s = Some(s);
@@ -747,6 +797,7 @@ impl<'a, 'b> CatFielddata<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -798,10 +849,16 @@ impl<'a, 'b> CatFielddata<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cat.fielddata")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cat Fielddata API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cat Health API"]
@@ -830,6 +887,7 @@ pub struct CatHealth<'a, 'b> {
     help: Option<bool>,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
     source: Option<&'b str>,
     time: Option<Time>,
@@ -853,6 +911,7 @@ impl<'a, 'b> CatHealth<'a, 'b> {
             help: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             s: None,
             source: None,
             time: None,
@@ -900,6 +959,11 @@ impl<'a, 'b> CatHealth<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Comma-separated list of column names or column aliases to sort by"]
     pub fn s(mut self, s: &'b [&'b str]) -> Self {
         self.s = Some(s);
@@ -930,6 +994,7 @@ impl<'a, 'b> CatHealth<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -981,10 +1046,16 @@ impl<'a, 'b> CatHealth<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cat.health")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cat Health API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cat Help API"]
@@ -1011,6 +1082,7 @@ pub struct CatHelp<'a, 'b> {
     help: Option<bool>,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
     source: Option<&'b str>,
 }
@@ -1029,6 +1101,7 @@ impl<'a, 'b> CatHelp<'a, 'b> {
             help: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             s: None,
             source: None,
         }
@@ -1063,6 +1136,11 @@ impl<'a, 'b> CatHelp<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Comma-separated list of column names or column aliases to sort by"]
     pub fn s(mut self, s: &'b [&'b str]) -> Self {
         self.s = Some(s);
@@ -1078,6 +1156,7 @@ impl<'a, 'b> CatHelp<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -1114,10 +1193,16 @@ impl<'a, 'b> CatHelp<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cat.help")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cat Help API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cat Indices API"]
@@ -1164,6 +1249,7 @@ pub struct CatIndices<'a, 'b> {
     master_timeout: Option<&'b str>,
     pretty: Option<bool>,
     pri: Option<bool>,
+    request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
     source: Option<&'b str>,
     time: Option<Time>,
@@ -1193,6 +1279,7 @@ impl<'a, 'b> CatIndices<'a, 'b> {
             master_timeout: None,
             pretty: None,
             pri: None,
+            request_timeout: None,
             s: None,
             source: None,
             time: None,
@@ -1274,6 +1361,11 @@ impl<'a, 'b> CatIndices<'a, 'b> {
         self.pri = Some(pri);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Comma-separated list of column names or column aliases to sort by"]
     pub fn s(mut self, s: &'b [&'b str]) -> Self {
         self.s = Some(s);
@@ -1299,6 +1391,7 @@ impl<'a, 'b> CatIndices<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -1368,10 +1461,16 @@ impl<'a, 'b> CatIndices<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cat.indices")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cat Indices API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cat Master API"]
@@ -1402,6 +1501,7 @@ pub struct CatMaster<'a, 'b> {
     local: Option<bool>,
     master_timeout: Option<&'b str>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
     source: Option<&'b str>,
     v: Option<bool>,
@@ -1425,6 +1525,7 @@ impl<'a, 'b> CatMaster<'a, 'b> {
             local: None,
             master_timeout: None,
             pretty: None,
+            request_timeout: None,
             s: None,
             source: None,
             v: None,
@@ -1480,6 +1581,11 @@ impl<'a, 'b> CatMaster<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Comma-separated list of column names or column aliases to sort by"]
     pub fn s(mut self, s: &'b [&'b str]) -> Self {
         self.s = Some(s);
@@ -1500,6 +1606,7 @@ impl<'a, 'b> CatMaster<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -1551,10 +1658,16 @@ impl<'a, 'b> CatMaster<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cat.master")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cat Master API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cat Ml Data Frame Analytics API"]
@@ -1594,6 +1707,7 @@ pub struct CatMlDataFrameAnalytics<'a, 'b> {
     help: Option<bool>,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
     source: Option<&'b str>,
     time: Option<Time>,
@@ -1618,6 +1732,7 @@ impl<'a, 'b> CatMlDataFrameAnalytics<'a, 'b> {
             help: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             s: None,
             source: None,
             time: None,
@@ -1674,6 +1789,11 @@ impl<'a, 'b> CatMlDataFrameAnalytics<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Comma-separated list of column names or column aliases to sort by"]
     pub fn s(mut self, s: &'b [&'b str]) -> Self {
         self.s = Some(s);
@@ -1699,6 +1819,7 @@ impl<'a, 'b> CatMlDataFrameAnalytics<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -1753,10 +1874,16 @@ impl<'a, 'b> CatMlDataFrameAnalytics<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cat.ml_data_frame_analytics")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cat Ml Data Frame Analytics API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cat Ml Datafeeds API"]
@@ -1796,6 +1923,7 @@ pub struct CatMlDatafeeds<'a, 'b> {
     help: Option<bool>,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
     source: Option<&'b str>,
     time: Option<Time>,
@@ -1819,6 +1947,7 @@ impl<'a, 'b> CatMlDatafeeds<'a, 'b> {
             help: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             s: None,
             source: None,
             time: None,
@@ -1870,6 +1999,11 @@ impl<'a, 'b> CatMlDatafeeds<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Comma-separated list of column names or column aliases to sort by"]
     pub fn s(mut self, s: &'b [&'b str]) -> Self {
         self.s = Some(s);
@@ -1895,6 +2029,7 @@ impl<'a, 'b> CatMlDatafeeds<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -1946,10 +2081,16 @@ impl<'a, 'b> CatMlDatafeeds<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cat.ml_datafeeds")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cat Ml Datafeeds API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cat Ml Jobs API"]
@@ -1990,6 +2131,7 @@ pub struct CatMlJobs<'a, 'b> {
     help: Option<bool>,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
     source: Option<&'b str>,
     time: Option<Time>,
@@ -2014,6 +2156,7 @@ impl<'a, 'b> CatMlJobs<'a, 'b> {
             help: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             s: None,
             source: None,
             time: None,
@@ -2070,6 +2213,11 @@ impl<'a, 'b> CatMlJobs<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Comma-separated list of column names or column aliases to sort by"]
     pub fn s(mut self, s: &'b [&'b str]) -> Self {
         self.s = Some(s);
@@ -2095,6 +2243,7 @@ impl<'a, 'b> CatMlJobs<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -2149,10 +2298,16 @@ impl<'a, 'b> CatMlJobs<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cat.ml_jobs")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cat Ml Jobs API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cat Ml Trained Models API"]
@@ -2194,6 +2349,7 @@ pub struct CatMlTrainedModels<'a, 'b> {
     help: Option<bool>,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
     size: Option<i32>,
     source: Option<&'b str>,
@@ -2220,6 +2376,7 @@ impl<'a, 'b> CatMlTrainedModels<'a, 'b> {
             help: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             s: None,
             size: None,
             source: None,
@@ -2282,6 +2439,11 @@ impl<'a, 'b> CatMlTrainedModels<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Comma-separated list of column names or column aliases to sort by"]
     pub fn s(mut self, s: &'b [&'b str]) -> Self {
         self.s = Some(s);
@@ -2312,6 +2474,7 @@ impl<'a, 'b> CatMlTrainedModels<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -2372,10 +2535,16 @@ impl<'a, 'b> CatMlTrainedModels<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cat.ml_trained_models")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cat Ml Trained Models API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cat Nodeattrs API"]
@@ -2406,6 +2575,7 @@ pub struct CatNodeattrs<'a, 'b> {
     local: Option<bool>,
     master_timeout: Option<&'b str>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
     source: Option<&'b str>,
     v: Option<bool>,
@@ -2429,6 +2599,7 @@ impl<'a, 'b> CatNodeattrs<'a, 'b> {
             local: None,
             master_timeout: None,
             pretty: None,
+            request_timeout: None,
             s: None,
             source: None,
             v: None,
@@ -2484,6 +2655,11 @@ impl<'a, 'b> CatNodeattrs<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Comma-separated list of column names or column aliases to sort by"]
     pub fn s(mut self, s: &'b [&'b str]) -> Self {
         self.s = Some(s);
@@ -2504,6 +2680,7 @@ impl<'a, 'b> CatNodeattrs<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -2555,10 +2732,16 @@ impl<'a, 'b> CatNodeattrs<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cat.nodeattrs")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cat Nodeattrs API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cat Nodes API"]
@@ -2591,6 +2774,7 @@ pub struct CatNodes<'a, 'b> {
     local: Option<bool>,
     master_timeout: Option<&'b str>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
     source: Option<&'b str>,
     time: Option<Time>,
@@ -2617,6 +2801,7 @@ impl<'a, 'b> CatNodes<'a, 'b> {
             local: None,
             master_timeout: None,
             pretty: None,
+            request_timeout: None,
             s: None,
             source: None,
             time: None,
@@ -2683,6 +2868,11 @@ impl<'a, 'b> CatNodes<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Comma-separated list of column names or column aliases to sort by"]
     pub fn s(mut self, s: &'b [&'b str]) -> Self {
         self.s = Some(s);
@@ -2708,6 +2898,7 @@ impl<'a, 'b> CatNodes<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -2768,10 +2959,16 @@ impl<'a, 'b> CatNodes<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cat.nodes")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cat Nodes API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cat Pending Tasks API"]
@@ -2802,6 +2999,7 @@ pub struct CatPendingTasks<'a, 'b> {
     local: Option<bool>,
     master_timeout: Option<&'b str>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
     source: Option<&'b str>,
     time: Option<Time>,
@@ -2826,6 +3024,7 @@ impl<'a, 'b> CatPendingTasks<'a, 'b> {
             local: None,
             master_timeout: None,
             pretty: None,
+            request_timeout: None,
             s: None,
             source: None,
             time: None,
@@ -2882,6 +3081,11 @@ impl<'a, 'b> CatPendingTasks<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Comma-separated list of column names or column aliases to sort by"]
     pub fn s(mut self, s: &'b [&'b str]) -> Self {
         self.s = Some(s);
@@ -2907,6 +3111,7 @@ impl<'a, 'b> CatPendingTasks<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -2961,10 +3166,16 @@ impl<'a, 'b> CatPendingTasks<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cat.pending_tasks")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cat Pending Tasks API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cat Plugins API"]
@@ -2995,6 +3206,7 @@ pub struct CatPlugins<'a, 'b> {
     local: Option<bool>,
     master_timeout: Option<&'b str>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
     source: Option<&'b str>,
     v: Option<bool>,
@@ -3018,6 +3230,7 @@ impl<'a, 'b> CatPlugins<'a, 'b> {
             local: None,
             master_timeout: None,
             pretty: None,
+            request_timeout: None,
             s: None,
             source: None,
             v: None,
@@ -3073,6 +3286,11 @@ impl<'a, 'b> CatPlugins<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Comma-separated list of column names or column aliases to sort by"]
     pub fn s(mut self, s: &'b [&'b str]) -> Self {
         self.s = Some(s);
@@ -3093,6 +3311,7 @@ impl<'a, 'b> CatPlugins<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -3144,10 +3363,16 @@ impl<'a, 'b> CatPlugins<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cat.plugins")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cat Plugins API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cat Recovery API"]
@@ -3191,6 +3416,7 @@ pub struct CatRecovery<'a, 'b> {
     human: Option<bool>,
     index: Option<&'b [&'b str]>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
     source: Option<&'b str>,
     time: Option<Time>,
@@ -3217,6 +3443,7 @@ impl<'a, 'b> CatRecovery<'a, 'b> {
             human: None,
             index: None,
             pretty: None,
+            request_timeout: None,
             s: None,
             source: None,
             time: None,
@@ -3283,6 +3510,11 @@ impl<'a, 'b> CatRecovery<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Comma-separated list of column names or column aliases to sort by"]
     pub fn s(mut self, s: &'b [&'b str]) -> Self {
         self.s = Some(s);
@@ -3308,6 +3540,7 @@ impl<'a, 'b> CatRecovery<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -3368,10 +3601,16 @@ impl<'a, 'b> CatRecovery<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cat.recovery")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cat Recovery API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cat Repositories API"]
@@ -3402,6 +3641,7 @@ pub struct CatRepositories<'a, 'b> {
     local: Option<bool>,
     master_timeout: Option<&'b str>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
     source: Option<&'b str>,
     v: Option<bool>,
@@ -3425,6 +3665,7 @@ impl<'a, 'b> CatRepositories<'a, 'b> {
             local: None,
             master_timeout: None,
             pretty: None,
+            request_timeout: None,
             s: None,
             source: None,
             v: None,
@@ -3480,6 +3721,11 @@ impl<'a, 'b> CatRepositories<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Comma-separated list of column names or column aliases to sort by"]
     pub fn s(mut self, s: &'b [&'b str]) -> Self {
         self.s = Some(s);
@@ -3500,6 +3746,7 @@ impl<'a, 'b> CatRepositories<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -3551,10 +3798,16 @@ impl<'a, 'b> CatRepositories<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cat.repositories")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cat Repositories API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cat Segments API"]
@@ -3595,6 +3848,7 @@ pub struct CatSegments<'a, 'b> {
     help: Option<bool>,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
     source: Option<&'b str>,
     v: Option<bool>,
@@ -3617,6 +3871,7 @@ impl<'a, 'b> CatSegments<'a, 'b> {
             help: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             s: None,
             source: None,
             v: None,
@@ -3667,6 +3922,11 @@ impl<'a, 'b> CatSegments<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Comma-separated list of column names or column aliases to sort by"]
     pub fn s(mut self, s: &'b [&'b str]) -> Self {
         self.s = Some(s);
@@ -3687,6 +3947,7 @@ impl<'a, 'b> CatSegments<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -3735,10 +3996,16 @@ impl<'a, 'b> CatSegments<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cat.segments")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cat Segments API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cat Shards API"]
@@ -3781,6 +4048,7 @@ pub struct CatShards<'a, 'b> {
     local: Option<bool>,
     master_timeout: Option<&'b str>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
     source: Option<&'b str>,
     time: Option<Time>,
@@ -3806,6 +4074,7 @@ impl<'a, 'b> CatShards<'a, 'b> {
             local: None,
             master_timeout: None,
             pretty: None,
+            request_timeout: None,
             s: None,
             source: None,
             time: None,
@@ -3867,6 +4136,11 @@ impl<'a, 'b> CatShards<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Comma-separated list of column names or column aliases to sort by"]
     pub fn s(mut self, s: &'b [&'b str]) -> Self {
         self.s = Some(s);
@@ -3892,6 +4166,7 @@ impl<'a, 'b> CatShards<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -3949,10 +4224,16 @@ impl<'a, 'b> CatShards<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cat.shards")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cat Shards API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cat Snapshots API"]
@@ -3994,6 +4275,7 @@ pub struct CatSnapshots<'a, 'b> {
     ignore_unavailable: Option<bool>,
     master_timeout: Option<&'b str>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
     source: Option<&'b str>,
     time: Option<Time>,
@@ -4018,6 +4300,7 @@ impl<'a, 'b> CatSnapshots<'a, 'b> {
             ignore_unavailable: None,
             master_timeout: None,
             pretty: None,
+            request_timeout: None,
             s: None,
             source: None,
             time: None,
@@ -4074,6 +4357,11 @@ impl<'a, 'b> CatSnapshots<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Comma-separated list of column names or column aliases to sort by"]
     pub fn s(mut self, s: &'b [&'b str]) -> Self {
         self.s = Some(s);
@@ -4099,6 +4387,7 @@ impl<'a, 'b> CatSnapshots<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -4153,10 +4442,16 @@ impl<'a, 'b> CatSnapshots<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cat.snapshots")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cat Snapshots API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cat Tasks API"]
@@ -4189,6 +4484,7 @@ pub struct CatTasks<'a, 'b> {
     node_id: Option<&'b [&'b str]>,
     parent_task: Option<i64>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
     source: Option<&'b str>,
     time: Option<Time>,
@@ -4215,6 +4511,7 @@ impl<'a, 'b> CatTasks<'a, 'b> {
             node_id: None,
             parent_task: None,
             pretty: None,
+            request_timeout: None,
             s: None,
             source: None,
             time: None,
@@ -4281,6 +4578,11 @@ impl<'a, 'b> CatTasks<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Comma-separated list of column names or column aliases to sort by"]
     pub fn s(mut self, s: &'b [&'b str]) -> Self {
         self.s = Some(s);
@@ -4306,6 +4608,7 @@ impl<'a, 'b> CatTasks<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -4372,10 +4675,16 @@ impl<'a, 'b> CatTasks<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cat.tasks")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cat Tasks API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cat Templates API"]
@@ -4415,6 +4724,7 @@ pub struct CatTemplates<'a, 'b> {
     local: Option<bool>,
     master_timeout: Option<&'b str>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
     source: Option<&'b str>,
     v: Option<bool>,
@@ -4438,6 +4748,7 @@ impl<'a, 'b> CatTemplates<'a, 'b> {
             local: None,
             master_timeout: None,
             pretty: None,
+            request_timeout: None,
             s: None,
             source: None,
             v: None,
@@ -4493,6 +4804,11 @@ impl<'a, 'b> CatTemplates<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Comma-separated list of column names or column aliases to sort by"]
     pub fn s(mut self, s: &'b [&'b str]) -> Self {
         self.s = Some(s);
@@ -4513,6 +4829,7 @@ impl<'a, 'b> CatTemplates<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -4564,10 +4881,16 @@ impl<'a, 'b> CatTemplates<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cat.templates")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cat Templates API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cat Thread Pool API"]
@@ -4609,6 +4932,7 @@ pub struct CatThreadPool<'a, 'b> {
     local: Option<bool>,
     master_timeout: Option<&'b str>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
     size: Option<Size>,
     source: Option<&'b str>,
@@ -4633,6 +4957,7 @@ impl<'a, 'b> CatThreadPool<'a, 'b> {
             local: None,
             master_timeout: None,
             pretty: None,
+            request_timeout: None,
             s: None,
             size: None,
             source: None,
@@ -4689,6 +5014,11 @@ impl<'a, 'b> CatThreadPool<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Comma-separated list of column names or column aliases to sort by"]
     pub fn s(mut self, s: &'b [&'b str]) -> Self {
         self.s = Some(s);
@@ -4714,6 +5044,7 @@ impl<'a, 'b> CatThreadPool<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -4768,10 +5099,16 @@ impl<'a, 'b> CatThreadPool<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cat.thread_pool")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cat Thread Pool API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cat Transforms API"]
@@ -4812,6 +5149,7 @@ pub struct CatTransforms<'a, 'b> {
     help: Option<bool>,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
     size: Option<i32>,
     source: Option<&'b str>,
@@ -4837,6 +5175,7 @@ impl<'a, 'b> CatTransforms<'a, 'b> {
             help: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             s: None,
             size: None,
             source: None,
@@ -4894,6 +5233,11 @@ impl<'a, 'b> CatTransforms<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Comma-separated list of column names or column aliases to sort by"]
     pub fn s(mut self, s: &'b [&'b str]) -> Self {
         self.s = Some(s);
@@ -4924,6 +5268,7 @@ impl<'a, 'b> CatTransforms<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -4981,10 +5326,16 @@ impl<'a, 'b> CatTransforms<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cat.transforms")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cat Transforms API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[doc = "Namespace client for Cat APIs"]
 pub struct Cat<'a> {
@@ -37,6 +37,7 @@ use crate::{
 use percent_encoding::percent_encode;
 use serde::Serialize;
 use std::borrow::Cow;
+use std::time::Duration;
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Ccr Delete Auto Follow Pattern API"]
 pub enum CcrDeleteAutoFollowPatternParts<'b> {
@@ -67,6 +68,7 @@ pub struct CcrDeleteAutoFollowPattern<'a, 'b> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
 }
 impl<'a, 'b> CcrDeleteAutoFollowPattern<'a, 'b> {
@@ -81,6 +83,7 @@ impl<'a, 'b> CcrDeleteAutoFollowPattern<'a, 'b> {
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             source: None,
         }
     }
@@ -109,6 +112,11 @@ impl<'a, 'b> CcrDeleteAutoFollowPattern<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -119,6 +127,7 @@ impl<'a, 'b> CcrDeleteAutoFollowPattern<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Delete;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -149,10 +158,16 @@ impl<'a, 'b> CcrDeleteAutoFollowPattern<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("ccr.delete_auto_follow_pattern")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Ccr Delete Auto Follow Pattern API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Ccr Follow API"]
@@ -187,6 +202,7 @@ pub struct CcrFollow<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
     wait_for_active_shards: Option<&'b str>,
 }
@@ -206,6 +222,7 @@ where
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             source: None,
             wait_for_active_shards: None,
         }
@@ -224,6 +241,7 @@ where
             headers: self.headers,
             human: self.human,
             pretty: self.pretty,
+            request_timeout: self.request_timeout,
             source: self.source,
             wait_for_active_shards: self.wait_for_active_shards,
         }
@@ -253,6 +271,11 @@ where
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -268,6 +291,7 @@ where
         let path = self.parts.url();
         let method = Method::Put;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -301,10 +325,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("ccr.follow")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Ccr Follow API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Ccr Follow Info API"]
@@ -339,6 +369,7 @@ pub struct CcrFollowInfo<'a, 'b> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
 }
 impl<'a, 'b> CcrFollowInfo<'a, 'b> {
@@ -353,6 +384,7 @@ impl<'a, 'b> CcrFollowInfo<'a, 'b> {
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             source: None,
         }
     }
@@ -381,6 +413,11 @@ impl<'a, 'b> CcrFollowInfo<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -391,6 +428,7 @@ impl<'a, 'b> CcrFollowInfo<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -421,10 +459,16 @@ impl<'a, 'b> CcrFollowInfo<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("ccr.follow_info")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Ccr Follow Info API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Ccr Follow Stats API"]
@@ -459,6 +503,7 @@ pub struct CcrFollowStats<'a, 'b> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
 }
 impl<'a, 'b> CcrFollowStats<'a, 'b> {
@@ -473,6 +518,7 @@ impl<'a, 'b> CcrFollowStats<'a, 'b> {
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             source: None,
         }
     }
@@ -501,6 +547,11 @@ impl<'a, 'b> CcrFollowStats<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -511,6 +562,7 @@ impl<'a, 'b> CcrFollowStats<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -541,10 +593,16 @@ impl<'a, 'b> CcrFollowStats<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("ccr.follow_stats")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Ccr Follow Stats API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Ccr Forget Follower API"]
@@ -579,6 +637,7 @@ pub struct CcrForgetFollower<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
 }
 impl<'a, 'b, B> CcrForgetFollower<'a, 'b, B>
@@ -597,6 +656,7 @@ where
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             source: None,
         }
     }
@@ -614,6 +674,7 @@ where
             headers: self.headers,
             human: self.human,
             pretty: self.pretty,
+            request_timeout: self.request_timeout,
             source: self.source,
         }
     }
@@ -642,6 +703,11 @@ where
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -652,6 +718,7 @@ where
         let path = self.parts.url();
         let method = Method::Post;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -682,10 +749,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("ccr.forget_follower")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Ccr Forget Follower API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Ccr Get Auto Follow Pattern API"]
@@ -720,6 +793,7 @@ pub struct CcrGetAutoFollowPattern<'a, 'b> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
 }
 impl<'a, 'b> CcrGetAutoFollowPattern<'a, 'b> {
@@ -734,6 +808,7 @@ impl<'a, 'b> CcrGetAutoFollowPattern<'a, 'b> {
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             source: None,
         }
     }
@@ -762,6 +837,11 @@ impl<'a, 'b> CcrGetAutoFollowPattern<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -772,6 +852,7 @@ impl<'a, 'b> CcrGetAutoFollowPattern<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -802,10 +883,16 @@ impl<'a, 'b> CcrGetAutoFollowPattern<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("ccr.get_auto_follow_pattern")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Ccr Get Auto Follow Pattern API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Ccr Pause Auto Follow Pattern API"]
@@ -839,6 +926,7 @@ pub struct CcrPauseAutoFollowPattern<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
 }
 impl<'a, 'b, B> CcrPauseAutoFollowPattern<'a, 'b, B>
@@ -857,6 +945,7 @@ where
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             source: None,
         }
     }
@@ -874,6 +963,7 @@ where
             headers: self.headers,
             human: self.human,
             pretty: self.pretty,
+            request_timeout: self.request_timeout,
             source: self.source,
         }
     }
@@ -902,6 +992,11 @@ where
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -912,6 +1007,7 @@ where
         let path = self.parts.url();
         let method = Method::Post;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -942,10 +1038,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("ccr.pause_auto_follow_pattern")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Ccr Pause Auto Follow Pattern API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Ccr Pause Follow API"]
@@ -980,6 +1082,7 @@ pub struct CcrPauseFollow<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
 }
 impl<'a, 'b, B> CcrPauseFollow<'a, 'b, B>
@@ -998,6 +1101,7 @@ where
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             source: None,
         }
     }
@@ -1015,6 +1119,7 @@ where
             headers: self.headers,
             human: self.human,
             pretty: self.pretty,
+            request_timeout: self.request_timeout,
             source: self.source,
         }
     }
@@ -1043,6 +1148,11 @@ where
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -1053,6 +1163,7 @@ where
         let path = self.parts.url();
         let method = Method::Post;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -1083,10 +1194,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("ccr.pause_follow")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Ccr Pause Follow API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Ccr Put Auto Follow Pattern API"]
@@ -1119,6 +1236,7 @@ pub struct CcrPutAutoFollowPattern<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
 }
 impl<'a, 'b, B> CcrPutAutoFollowPattern<'a, 'b, B>
@@ -1137,6 +1255,7 @@ where
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             source: None,
         }
     }
@@ -1154,6 +1273,7 @@ where
             headers: self.headers,
             human: self.human,
             pretty: self.pretty,
+            request_timeout: self.request_timeout,
             source: self.source,
         }
     }
@@ -1182,6 +1302,11 @@ where
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -1192,6 +1317,7 @@ where
         let path = self.parts.url();
         let method = Method::Put;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -1222,10 +1348,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("ccr.put_auto_follow_pattern")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Ccr Put Auto Follow Pattern API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Ccr Resume Auto Follow Pattern API"]
@@ -1259,6 +1391,7 @@ pub struct CcrResumeAutoFollowPattern<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
 }
 impl<'a, 'b, B> CcrResumeAutoFollowPattern<'a, 'b, B>
@@ -1277,6 +1410,7 @@ where
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             source: None,
         }
     }
@@ -1294,6 +1428,7 @@ where
             headers: self.headers,
             human: self.human,
             pretty: self.pretty,
+            request_timeout: self.request_timeout,
             source: self.source,
         }
     }
@@ -1322,6 +1457,11 @@ where
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -1332,6 +1472,7 @@ where
         let path = self.parts.url();
         let method = Method::Post;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -1362,10 +1503,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("ccr.resume_auto_follow_pattern")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Ccr Resume Auto Follow Pattern API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Ccr Resume Follow API"]
@@ -1400,6 +1547,7 @@ pub struct CcrResumeFollow<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
 }
 impl<'a, 'b, B> CcrResumeFollow<'a, 'b, B>
@@ -1418,6 +1566,7 @@ where
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             source: None,
         }
     }
@@ -1435,6 +1584,7 @@ where
             headers: self.headers,
             human: self.human,
             pretty: self.pretty,
+            request_timeout: self.request_timeout,
             source: self.source,
         }
     }
@@ -1463,6 +1613,11 @@ where
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -1473,6 +1628,7 @@ where
         let path = self.parts.url();
         let method = Method::Post;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -1503,10 +1659,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("ccr.resume_follow")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Ccr Resume Follow API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Ccr Stats API"]
@@ -1532,6 +1694,7 @@ pub struct CcrStats<'a, 'b> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
 }
 impl<'a, 'b> CcrStats<'a, 'b> {
@@ -1546,6 +1709,7 @@ impl<'a, 'b> CcrStats<'a, 'b> {
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             source: None,
         }
     }
@@ -1574,6 +1738,11 @@ impl<'a, 'b> CcrStats<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -1584,6 +1753,7 @@ impl<'a, 'b> CcrStats<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -1614,10 +1784,16 @@ impl<'a, 'b> CcrStats<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("ccr.stats")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Ccr Stats API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Ccr Unfollow API"]
@@ -1652,6 +1828,7 @@ pub struct CcrUnfollow<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
 }
 impl<'a, 'b, B> CcrUnfollow<'a, 'b, B>
@@ -1670,6 +1847,7 @@ where
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             source: None,
         }
     }
@@ -1687,6 +1865,7 @@ where
             headers: self.headers,
             human: self.human,
             pretty: self.pretty,
+            request_timeout: self.request_timeout,
             source: self.source,
         }
     }
@@ -1715,6 +1894,11 @@ where
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -1725,6 +1909,7 @@ where
         let path = self.parts.url();
         let method = Method::Post;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -1755,10 +1940,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("ccr.unfollow")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Ccr Unfollow API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[doc = "Namespace client for Cross Cluster Replication APIs"]
 pub struct Ccr<'a> {
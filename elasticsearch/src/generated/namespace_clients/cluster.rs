@@ -37,6 +37,7 @@ use crate::{
 use percent_encoding::percent_encode;
 use serde::Serialize;
 use std::borrow::Cow;
+use std::time::Duration;
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cluster Allocation Explain API"]
 pub enum ClusterAllocationExplainParts {
@@ -64,6 +65,7 @@ pub struct ClusterAllocationExplain<'a, 'b, B> {
     include_disk_info: Option<bool>,
     include_yes_decisions: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
 }
 impl<'a, 'b, B> ClusterAllocationExplain<'a, 'b, B>
@@ -84,6 +86,7 @@ where
             include_disk_info: None,
             include_yes_decisions: None,
             pretty: None,
+            request_timeout: None,
             source: None,
         }
     }
@@ -103,6 +106,7 @@ where
             include_disk_info: self.include_disk_info,
             include_yes_decisions: self.include_yes_decisions,
             pretty: self.pretty,
+            request_timeout: self.request_timeout,
             source: self.source,
         }
     }
@@ -141,6 +145,11 @@ where
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -154,6 +163,7 @@ where
             None => Method::Get,
         };
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -190,10 +200,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cluster.allocation_explain")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cluster Allocation Explain API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cluster Get Settings API"]
@@ -222,6 +238,7 @@ pub struct ClusterGetSettings<'a, 'b> {
     include_defaults: Option<bool>,
     master_timeout: Option<&'b str>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
     timeout: Option<&'b str>,
 }
@@ -240,6 +257,7 @@ impl<'a, 'b> ClusterGetSettings<'a, 'b> {
             include_defaults: None,
             master_timeout: None,
             pretty: None,
+            request_timeout: None,
             source: None,
             timeout: None,
         }
@@ -284,6 +302,11 @@ impl<'a, 'b> ClusterGetSettings<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -299,6 +322,7 @@ impl<'a, 'b> ClusterGetSettings<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -341,10 +365,16 @@ impl<'a, 'b> ClusterGetSettings<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cluster.get_settings")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cluster Get Settings API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cluster Health API"]
@@ -385,6 +415,7 @@ pub struct ClusterHealth<'a, 'b> {
     local: Option<bool>,
     master_timeout: Option<&'b str>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
     timeout: Option<&'b str>,
     wait_for_active_shards: Option<&'b str>,
@@ -410,6 +441,7 @@ impl<'a, 'b> ClusterHealth<'a, 'b> {
             local: None,
             master_timeout: None,
             pretty: None,
+            request_timeout: None,
             source: None,
             timeout: None,
             wait_for_active_shards: None,
@@ -465,6 +497,11 @@ impl<'a, 'b> ClusterHealth<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -513,6 +550,7 @@ impl<'a, 'b> ClusterHealth<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -576,10 +614,16 @@ impl<'a, 'b> ClusterHealth<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cluster.health")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cluster Health API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cluster Pending Tasks API"]
@@ -607,6 +651,7 @@ pub struct ClusterPendingTasks<'a, 'b> {
     local: Option<bool>,
     master_timeout: Option<&'b str>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
 }
 impl<'a, 'b> ClusterPendingTasks<'a, 'b> {
@@ -623,6 +668,7 @@ impl<'a, 'b> ClusterPendingTasks<'a, 'b> {
             local: None,
             master_timeout: None,
             pretty: None,
+            request_timeout: None,
             source: None,
         }
     }
@@ -661,6 +707,11 @@ impl<'a, 'b> ClusterPendingTasks<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -671,6 +722,7 @@ impl<'a, 'b> ClusterPendingTasks<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -707,10 +759,16 @@ impl<'a, 'b> ClusterPendingTasks<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cluster.pending_tasks")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cluster Pending Tasks API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cluster Put Settings API"]
@@ -739,6 +797,7 @@ pub struct ClusterPutSettings<'a, 'b, B> {
     human: Option<bool>,
     master_timeout: Option<&'b str>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
     timeout: Option<&'b str>,
 }
@@ -760,6 +819,7 @@ where
             human: None,
             master_timeout: None,
             pretty: None,
+            request_timeout: None,
             source: None,
             timeout: None,
         }
@@ -780,6 +840,7 @@ where
             human: self.human,
             master_timeout: self.master_timeout,
             pretty: self.pretty,
+            request_timeout: self.request_timeout,
             source: self.source,
             timeout: self.timeout,
         }
@@ -819,6 +880,11 @@ where
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -834,6 +900,7 @@ where
         let path = self.parts.url();
         let method = Method::Put;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -873,10 +940,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cluster.put_settings")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cluster Put Settings API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cluster Remote Info API"]
@@ -902,6 +975,7 @@ pub struct ClusterRemoteInfo<'a, 'b> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
 }
 impl<'a, 'b> ClusterRemoteInfo<'a, 'b> {
@@ -916,6 +990,7 @@ impl<'a, 'b> ClusterRemoteInfo<'a, 'b> {
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             source: None,
         }
     }
@@ -944,6 +1019,11 @@ impl<'a, 'b> ClusterRemoteInfo<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -954,6 +1034,7 @@ impl<'a, 'b> ClusterRemoteInfo<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -984,10 +1065,16 @@ impl<'a, 'b> ClusterRemoteInfo<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cluster.remote_info")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cluster Remote Info API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cluster Reroute API"]
@@ -1018,6 +1105,7 @@ pub struct ClusterReroute<'a, 'b, B> {
     master_timeout: Option<&'b str>,
     metric: Option<&'b [&'b str]>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     retry_failed: Option<bool>,
     source: Option<&'b str>,
     timeout: Option<&'b str>,
@@ -1042,6 +1130,7 @@ where
             master_timeout: None,
             metric: None,
             pretty: None,
+            request_timeout: None,
             retry_failed: None,
             source: None,
             timeout: None,
@@ -1065,6 +1154,7 @@ where
             master_timeout: self.master_timeout,
             metric: self.metric,
             pretty: self.pretty,
+            request_timeout: self.request_timeout,
             retry_failed: self.retry_failed,
             source: self.source,
             timeout: self.timeout,
@@ -1115,6 +1205,11 @@ where
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Retries allocation of shards that are blocked due to too many subsequent allocation failures"]
     pub fn retry_failed(mut self, retry_failed: bool) -> Self {
         self.retry_failed = Some(retry_failed);
@@ -1135,6 +1230,7 @@ where
         let path = self.parts.url();
         let method = Method::Post;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -1183,10 +1279,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cluster.reroute")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cluster Reroute API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cluster State API"]
@@ -1246,6 +1348,7 @@ pub struct ClusterState<'a, 'b> {
     local: Option<bool>,
     master_timeout: Option<&'b str>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
     wait_for_metadata_version: Option<i64>,
     wait_for_timeout: Option<&'b str>,
@@ -1268,6 +1371,7 @@ impl<'a, 'b> ClusterState<'a, 'b> {
             local: None,
             master_timeout: None,
             pretty: None,
+            request_timeout: None,
             source: None,
             wait_for_metadata_version: None,
             wait_for_timeout: None,
@@ -1328,6 +1432,11 @@ impl<'a, 'b> ClusterState<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -1348,6 +1457,7 @@ impl<'a, 'b> ClusterState<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -1402,10 +1512,16 @@ impl<'a, 'b> ClusterState<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cluster.state")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cluster State API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Cluster Stats API"]
@@ -1443,6 +1559,7 @@ pub struct ClusterStats<'a, 'b> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
     timeout: Option<&'b str>,
 }
@@ -1459,6 +1576,7 @@ impl<'a, 'b> ClusterStats<'a, 'b> {
             flat_settings: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             source: None,
             timeout: None,
         }
@@ -1493,6 +1611,11 @@ impl<'a, 'b> ClusterStats<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -1508,6 +1631,7 @@ impl<'a, 'b> ClusterStats<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -1544,10 +1668,16 @@ impl<'a, 'b> ClusterStats<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("cluster.stats")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Cluster Stats API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[doc = "Namespace client for Cluster APIs"]
 pub struct Cluster<'a> {
@@ -37,6 +37,7 @@ use crate::{
 use percent_encoding::percent_encode;
 use serde::Serialize;
 use std::borrow::Cow;
+use std::time::Duration;
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Enrich Delete Policy API"]
 pub enum EnrichDeletePolicyParts<'b> {
@@ -67,6 +68,7 @@ pub struct EnrichDeletePolicy<'a, 'b> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
 }
 impl<'a, 'b> EnrichDeletePolicy<'a, 'b> {
@@ -81,6 +83,7 @@ impl<'a, 'b> EnrichDeletePolicy<'a, 'b> {
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             source: None,
         }
     }
@@ -109,6 +112,11 @@ impl<'a, 'b> EnrichDeletePolicy<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -119,6 +127,7 @@ impl<'a, 'b> EnrichDeletePolicy<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Delete;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -149,10 +158,16 @@ impl<'a, 'b> EnrichDeletePolicy<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("enrich.delete_policy")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Enrich Delete Policy API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Enrich Execute Policy API"]
@@ -186,6 +201,7 @@ pub struct EnrichExecutePolicy<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
     wait_for_completion: Option<bool>,
 }
@@ -205,6 +221,7 @@ where
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             source: None,
             wait_for_completion: None,
         }
@@ -223,6 +240,7 @@ where
             headers: self.headers,
             human: self.human,
             pretty: self.pretty,
+            request_timeout: self.request_timeout,
             source: self.source,
             wait_for_completion: self.wait_for_completion,
         }
@@ -252,6 +270,11 @@ where
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -267,6 +290,7 @@ where
         let path = self.parts.url();
         let method = Method::Put;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -300,10 +324,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("enrich.execute_policy")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Enrich Execute Policy API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Enrich Get Policy API"]
@@ -340,6 +370,7 @@ pub struct EnrichGetPolicy<'a, 'b> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
 }
 impl<'a, 'b> EnrichGetPolicy<'a, 'b> {
@@ -354,6 +385,7 @@ impl<'a, 'b> EnrichGetPolicy<'a, 'b> {
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             source: None,
         }
     }
@@ -382,6 +414,11 @@ impl<'a, 'b> EnrichGetPolicy<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -392,6 +429,7 @@ impl<'a, 'b> EnrichGetPolicy<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -422,10 +460,16 @@ impl<'a, 'b> EnrichGetPolicy<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("enrich.get_policy")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Enrich Get Policy API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Enrich Put Policy API"]
@@ -458,6 +502,7 @@ pub struct EnrichPutPolicy<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
 }
 impl<'a, 'b, B> EnrichPutPolicy<'a, 'b, B>
@@ -476,6 +521,7 @@ where
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             source: None,
         }
     }
@@ -493,6 +539,7 @@ where
             headers: self.headers,
             human: self.human,
             pretty: self.pretty,
+            request_timeout: self.request_timeout,
             source: self.source,
         }
     }
@@ -521,6 +568,11 @@ where
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -531,6 +583,7 @@ where
         let path = self.parts.url();
         let method = Method::Put;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -561,10 +614,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("enrich.put_policy")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Enrich Put Policy API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Enrich Stats API"]
@@ -590,6 +649,7 @@ pub struct EnrichStats<'a, 'b> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
 }
 impl<'a, 'b> EnrichStats<'a, 'b> {
@@ -604,6 +664,7 @@ impl<'a, 'b> EnrichStats<'a, 'b> {
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             source: None,
         }
     }
@@ -632,6 +693,11 @@ impl<'a, 'b> EnrichStats<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -642,6 +708,7 @@ impl<'a, 'b> EnrichStats<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -672,10 +739,16 @@ impl<'a, 'b> EnrichStats<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("enrich.stats")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Enrich Stats API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[doc = "Namespace client for Enrich APIs"]
 pub struct Enrich<'a> {
@@ -37,6 +37,7 @@ use crate::{
 use percent_encoding::percent_encode;
 use serde::Serialize;
 use std::borrow::Cow;
+use std::time::Duration;
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Bulk API"]
 pub enum BulkParts<'b> {
@@ -92,6 +93,7 @@ pub struct Bulk<'a, 'b, B> {
     pipeline: Option<&'b str>,
     pretty: Option<bool>,
     refresh: Option<Refresh>,
+    request_timeout: Option<Duration>,
     routing: Option<&'b str>,
     source: Option<&'b str>,
     timeout: Option<&'b str>,
@@ -119,6 +121,7 @@ where
             pipeline: None,
             pretty: None,
             refresh: None,
+            request_timeout: None,
             routing: None,
             source: None,
             timeout: None,
@@ -160,6 +163,7 @@ where
             pipeline: self.pipeline,
             pretty: self.pretty,
             refresh: self.refresh,
+            request_timeout: self.request_timeout,
             routing: self.routing,
             source: self.source,
             timeout: self.timeout,
@@ -202,6 +206,11 @@ where
         self.refresh = Some(refresh);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Specific routing value"]
     pub fn routing(mut self, routing: &'b str) -> Self {
         self.routing = Some(routing);
@@ -232,6 +241,7 @@ where
         let path = self.parts.url();
         let method = Method::Post;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -298,10 +308,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("bulk")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Bulk API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Clear Scroll API"]
@@ -339,6 +355,7 @@ pub struct ClearScroll<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
 }
 impl<'a, 'b, B> ClearScroll<'a, 'b, B>
@@ -357,6 +374,7 @@ where
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             source: None,
         }
     }
@@ -374,6 +392,7 @@ where
             headers: self.headers,
             human: self.human,
             pretty: self.pretty,
+            request_timeout: self.request_timeout,
             source: self.source,
         }
     }
@@ -402,6 +421,11 @@ where
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -412,6 +436,7 @@ where
         let path = self.parts.url();
         let method = Method::Delete;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -442,10 +467,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("clear_scroll")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Clear Scroll API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Count API"]
@@ -512,6 +543,7 @@ pub struct Count<'a, 'b, B> {
     preference: Option<&'b str>,
     pretty: Option<bool>,
     q: Option<&'b str>,
+    request_timeout: Option<Duration>,
     routing: Option<&'b [&'b str]>,
     source: Option<&'b str>,
     terminate_after: Option<i64>,
@@ -544,6 +576,7 @@ where
             preference: None,
             pretty: None,
             q: None,
+            request_timeout: None,
             routing: None,
             source: None,
             terminate_after: None,
@@ -590,6 +623,7 @@ where
             preference: self.preference,
             pretty: self.pretty,
             q: self.q,
+            request_timeout: self.request_timeout,
             routing: self.routing,
             source: self.source,
             terminate_after: self.terminate_after,
@@ -665,6 +699,11 @@ where
         self.q = Some(q);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "A comma-separated list of specific routing values"]
     pub fn routing(mut self, routing: &'b [&'b str]) -> Self {
         self.routing = Some(routing);
@@ -688,6 +727,7 @@ where
             None => Method::Get,
         };
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -763,10 +803,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("count")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Count API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Create API"]
@@ -824,6 +870,7 @@ pub struct Create<'a, 'b, B> {
     pipeline: Option<&'b str>,
     pretty: Option<bool>,
     refresh: Option<Refresh>,
+    request_timeout: Option<Duration>,
     routing: Option<&'b str>,
     source: Option<&'b str>,
     timeout: Option<&'b str>,
@@ -849,6 +896,7 @@ where
             pipeline: None,
             pretty: None,
             refresh: None,
+            request_timeout: None,
             routing: None,
             source: None,
             timeout: None,
@@ -873,6 +921,7 @@ where
             pipeline: self.pipeline,
             pretty: self.pretty,
             refresh: self.refresh,
+            request_timeout: self.request_timeout,
             routing: self.routing,
             source: self.source,
             timeout: self.timeout,
@@ -916,6 +965,11 @@ where
         self.refresh = Some(refresh);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Specific routing value"]
     pub fn routing(mut self, routing: &'b str) -> Self {
         self.routing = Some(routing);
@@ -951,6 +1005,7 @@ where
         let path = self.parts.url();
         let method = Method::Post;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -1002,10 +1057,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("create")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Create API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Delete API"]
@@ -1062,6 +1123,7 @@ pub struct Delete<'a, 'b> {
     if_seq_no: Option<i64>,
     pretty: Option<bool>,
     refresh: Option<Refresh>,
+    request_timeout: Option<Duration>,
     routing: Option<&'b str>,
     source: Option<&'b str>,
     timeout: Option<&'b str>,
@@ -1084,6 +1146,7 @@ impl<'a, 'b> Delete<'a, 'b> {
             if_seq_no: None,
             pretty: None,
             refresh: None,
+            request_timeout: None,
             routing: None,
             source: None,
             timeout: None,
@@ -1132,6 +1195,11 @@ impl<'a, 'b> Delete<'a, 'b> {
         self.refresh = Some(refresh);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Specific routing value"]
     pub fn routing(mut self, routing: &'b str) -> Self {
         self.routing = Some(routing);
@@ -1167,6 +1235,7 @@ impl<'a, 'b> Delete<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Delete;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -1221,10 +1290,16 @@ impl<'a, 'b> Delete<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("delete")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Delete API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Delete By Query API"]
@@ -1294,6 +1369,7 @@ pub struct DeleteByQuery<'a, 'b, B> {
     q: Option<&'b str>,
     refresh: Option<bool>,
     request_cache: Option<bool>,
+    request_timeout: Option<Duration>,
     requests_per_second: Option<i64>,
     routing: Option<&'b [&'b str]>,
     scroll: Option<&'b str>,
@@ -1345,6 +1421,7 @@ where
             q: None,
             refresh: None,
             request_cache: None,
+            request_timeout: None,
             requests_per_second: None,
             routing: None,
             scroll: None,
@@ -1425,6 +1502,7 @@ where
             q: self.q,
             refresh: self.refresh,
             request_cache: self.request_cache,
+            request_timeout: self.request_timeout,
             requests_per_second: self.requests_per_second,
             routing: self.routing,
             scroll: self.scroll,
@@ -1528,6 +1606,11 @@ where
         self.request_cache = Some(request_cache);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The throttle for this request in sub-requests per second. -1 means no throttle."]
     pub fn requests_per_second(mut self, requests_per_second: i64) -> Self {
         self.requests_per_second = Some(requests_per_second);
@@ -1613,6 +1696,7 @@ where
         let path = self.parts.url();
         let method = Method::Post;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -1754,10 +1838,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("delete_by_query")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Delete By Query API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Delete By Query Rethrottle API"]
@@ -1792,6 +1882,7 @@ pub struct DeleteByQueryRethrottle<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     requests_per_second: Option<i64>,
     source: Option<&'b str>,
 }
@@ -1811,6 +1902,7 @@ where
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             requests_per_second: None,
             source: None,
         }
@@ -1829,6 +1921,7 @@ where
             headers: self.headers,
             human: self.human,
             pretty: self.pretty,
+            request_timeout: self.request_timeout,
             requests_per_second: self.requests_per_second,
             source: self.source,
         }
@@ -1858,6 +1951,11 @@ where
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The throttle to set on this request in floating sub-requests per second. -1 means set no throttle."]
     pub fn requests_per_second(mut self, requests_per_second: i64) -> Self {
         self.requests_per_second = Some(requests_per_second);
@@ -1873,6 +1971,7 @@ where
         let path = self.parts.url();
         let method = Method::Post;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -1906,10 +2005,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("delete_by_query_rethrottle")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Delete By Query Rethrottle API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Delete Script API"]
@@ -1942,6 +2047,7 @@ pub struct DeleteScript<'a, 'b> {
     human: Option<bool>,
     master_timeout: Option<&'b str>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
     timeout: Option<&'b str>,
 }
@@ -1958,6 +2064,7 @@ impl<'a, 'b> DeleteScript<'a, 'b> {
             human: None,
             master_timeout: None,
             pretty: None,
+            request_timeout: None,
             source: None,
             timeout: None,
         }
@@ -1992,6 +2099,11 @@ impl<'a, 'b> DeleteScript<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -2007,6 +2119,7 @@ impl<'a, 'b> DeleteScript<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Delete;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -2043,10 +2156,16 @@ impl<'a, 'b> DeleteScript<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("delete_script")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Delete Script API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Exists API"]
@@ -2106,6 +2225,7 @@ pub struct Exists<'a, 'b> {
     pretty: Option<bool>,
     realtime: Option<bool>,
     refresh: Option<bool>,
+    request_timeout: Option<Duration>,
     routing: Option<&'b str>,
     source: Option<&'b str>,
     stored_fields: Option<&'b [&'b str]>,
@@ -2130,6 +2250,7 @@ impl<'a, 'b> Exists<'a, 'b> {
             pretty: None,
             realtime: None,
             refresh: None,
+            request_timeout: None,
             routing: None,
             source: None,
             stored_fields: None,
@@ -2192,6 +2313,11 @@ impl<'a, 'b> Exists<'a, 'b> {
         self.refresh = Some(refresh);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Specific routing value"]
     pub fn routing(mut self, routing: &'b str) -> Self {
         self.routing = Some(routing);
@@ -2222,6 +2348,7 @@ impl<'a, 'b> Exists<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Head;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -2294,10 +2421,16 @@ impl<'a, 'b> Exists<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("exists")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Exists API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Exists Source API"]
@@ -2358,6 +2491,7 @@ pub struct ExistsSource<'a, 'b> {
     pretty: Option<bool>,
     realtime: Option<bool>,
     refresh: Option<bool>,
+    request_timeout: Option<Duration>,
     routing: Option<&'b str>,
     source: Option<&'b str>,
     version: Option<i64>,
@@ -2381,6 +2515,7 @@ impl<'a, 'b> ExistsSource<'a, 'b> {
             pretty: None,
             realtime: None,
             refresh: None,
+            request_timeout: None,
             routing: None,
             source: None,
             version: None,
@@ -2442,6 +2577,11 @@ impl<'a, 'b> ExistsSource<'a, 'b> {
         self.refresh = Some(refresh);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Specific routing value"]
     pub fn routing(mut self, routing: &'b str) -> Self {
         self.routing = Some(routing);
@@ -2467,6 +2607,7 @@ impl<'a, 'b> ExistsSource<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Head;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -2533,10 +2674,16 @@ impl<'a, 'b> ExistsSource<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("exists_source")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Exists Source API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Explain API"]
@@ -2602,6 +2749,7 @@ pub struct Explain<'a, 'b, B> {
     preference: Option<&'b str>,
     pretty: Option<bool>,
     q: Option<&'b str>,
+    request_timeout: Option<Duration>,
     routing: Option<&'b str>,
     source: Option<&'b str>,
     stored_fields: Option<&'b [&'b str]>,
@@ -2632,6 +2780,7 @@ where
             preference: None,
             pretty: None,
             q: None,
+            request_timeout: None,
             routing: None,
             source: None,
             stored_fields: None,
@@ -2686,6 +2835,7 @@ where
             preference: self.preference,
             pretty: self.pretty,
             q: self.q,
+            request_timeout: self.request_timeout,
             routing: self.routing,
             source: self.source,
             stored_fields: self.stored_fields,
@@ -2741,6 +2891,11 @@ where
         self.q = Some(q);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Specific routing value"]
     pub fn routing(mut self, routing: &'b str) -> Self {
         self.routing = Some(routing);
@@ -2764,6 +2919,7 @@ where
             None => Method::Get,
         };
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -2842,10 +2998,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("explain")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Explain API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Field Caps API"]
@@ -2889,6 +3051,7 @@ pub struct FieldCaps<'a, 'b, B> {
     ignore_unavailable: Option<bool>,
     include_unmapped: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
 }
 impl<'a, 'b, B> FieldCaps<'a, 'b, B>
@@ -2912,6 +3075,7 @@ where
             ignore_unavailable: None,
             include_unmapped: None,
             pretty: None,
+            request_timeout: None,
             source: None,
         }
     }
@@ -2939,6 +3103,7 @@ where
             ignore_unavailable: self.ignore_unavailable,
             include_unmapped: self.include_unmapped,
             pretty: self.pretty,
+            request_timeout: self.request_timeout,
             source: self.source,
         }
     }
@@ -2987,6 +3152,11 @@ where
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -3000,6 +3170,7 @@ where
             None => Method::Get,
         };
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -3045,10 +3216,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("field_caps")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Field Caps API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Get API"]
@@ -3108,6 +3285,7 @@ pub struct Get<'a, 'b> {
     pretty: Option<bool>,
     realtime: Option<bool>,
     refresh: Option<bool>,
+    request_timeout: Option<Duration>,
     routing: Option<&'b str>,
     source: Option<&'b str>,
     stored_fields: Option<&'b [&'b str]>,
@@ -3132,6 +3310,7 @@ impl<'a, 'b> Get<'a, 'b> {
             pretty: None,
             realtime: None,
             refresh: None,
+            request_timeout: None,
             routing: None,
             source: None,
             stored_fields: None,
@@ -3194,6 +3373,11 @@ impl<'a, 'b> Get<'a, 'b> {
         self.refresh = Some(refresh);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Specific routing value"]
     pub fn routing(mut self, routing: &'b str) -> Self {
         self.routing = Some(routing);
@@ -3224,6 +3408,7 @@ impl<'a, 'b> Get<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -3296,10 +3481,16 @@ impl<'a, 'b> Get<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("get")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Get API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Get Script API"]
@@ -3332,6 +3523,7 @@ pub struct GetScript<'a, 'b> {
     human: Option<bool>,
     master_timeout: Option<&'b str>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
 }
 impl<'a, 'b> GetScript<'a, 'b> {
@@ -3347,6 +3539,7 @@ impl<'a, 'b> GetScript<'a, 'b> {
             human: None,
             master_timeout: None,
             pretty: None,
+            request_timeout: None,
             source: None,
         }
     }
@@ -3380,6 +3573,11 @@ impl<'a, 'b> GetScript<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -3390,6 +3588,7 @@ impl<'a, 'b> GetScript<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -3423,10 +3622,16 @@ impl<'a, 'b> GetScript<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("get_script")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Get Script API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Get Source API"]
@@ -3487,6 +3692,7 @@ pub struct GetSource<'a, 'b> {
     pretty: Option<bool>,
     realtime: Option<bool>,
     refresh: Option<bool>,
+    request_timeout: Option<Duration>,
     routing: Option<&'b str>,
     source: Option<&'b str>,
     version: Option<i64>,
@@ -3510,6 +3716,7 @@ impl<'a, 'b> GetSource<'a, 'b> {
             pretty: None,
             realtime: None,
             refresh: None,
+            request_timeout: None,
             routing: None,
             source: None,
             version: None,
@@ -3571,6 +3778,11 @@ impl<'a, 'b> GetSource<'a, 'b> {
         self.refresh = Some(refresh);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Specific routing value"]
     pub fn routing(mut self, routing: &'b str) -> Self {
         self.routing = Some(routing);
@@ -3596,6 +3808,7 @@ impl<'a, 'b> GetSource<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -3662,10 +3875,16 @@ impl<'a, 'b> GetSource<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("get_source")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Get Source API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Index API"]
@@ -3749,6 +3968,7 @@ pub struct Index<'a, 'b, B> {
     pipeline: Option<&'b str>,
     pretty: Option<bool>,
     refresh: Option<Refresh>,
+    request_timeout: Option<Duration>,
     routing: Option<&'b str>,
     source: Option<&'b str>,
     timeout: Option<&'b str>,
@@ -3777,6 +3997,7 @@ where
             pipeline: None,
             pretty: None,
             refresh: None,
+            request_timeout: None,
             routing: None,
             source: None,
             timeout: None,
@@ -3804,6 +4025,7 @@ where
             pipeline: self.pipeline,
             pretty: self.pretty,
             refresh: self.refresh,
+            request_timeout: self.request_timeout,
             routing: self.routing,
             source: self.source,
             timeout: self.timeout,
@@ -3862,6 +4084,11 @@ where
         self.refresh = Some(refresh);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Specific routing value"]
     pub fn routing(mut self, routing: &'b str) -> Self {
         self.routing = Some(routing);
@@ -3897,6 +4124,7 @@ where
         let path = self.parts.url();
         let method = Method::Post;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -3957,10 +4185,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("index")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Index API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Info API"]
@@ -3986,6 +4220,7 @@ pub struct Info<'a, 'b> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
 }
 impl<'a, 'b> Info<'a, 'b> {
@@ -4000,6 +4235,7 @@ impl<'a, 'b> Info<'a, 'b> {
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             source: None,
         }
     }
@@ -4028,6 +4264,11 @@ impl<'a, 'b> Info<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -4038,6 +4279,7 @@ impl<'a, 'b> Info<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Get;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -4068,10 +4310,16 @@ impl<'a, 'b> Info<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("info")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Info API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Mget API"]
@@ -4129,6 +4377,7 @@ pub struct Mget<'a, 'b, B> {
     pretty: Option<bool>,
     realtime: Option<bool>,
     refresh: Option<bool>,
+    request_timeout: Option<Duration>,
     routing: Option<&'b str>,
     source: Option<&'b str>,
     stored_fields: Option<&'b [&'b str]>,
@@ -4155,6 +4404,7 @@ where
             pretty: None,
             realtime: None,
             refresh: None,
+            request_timeout: None,
             routing: None,
             source: None,
             stored_fields: None,
@@ -4195,6 +4445,7 @@ where
             pretty: self.pretty,
             realtime: self.realtime,
             refresh: self.refresh,
+            request_timeout: self.request_timeout,
             routing: self.routing,
             source: self.source,
             stored_fields: self.stored_fields,
@@ -4240,6 +4491,11 @@ where
         self.refresh = Some(refresh);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Specific routing value"]
     pub fn routing(mut self, routing: &'b str) -> Self {
         self.routing = Some(routing);
@@ -4263,6 +4519,7 @@ where
             None => Method::Get,
         };
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -4329,10 +4586,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("mget")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Mget API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Msearch API"]
@@ -4391,6 +4654,7 @@ pub struct Msearch<'a, 'b, B> {
     max_concurrent_shard_requests: Option<i64>,
     pre_filter_shard_size: Option<i64>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     rest_total_hits_as_int: Option<bool>,
     search_type: Option<SearchType>,
     source: Option<&'b str>,
@@ -4416,6 +4680,7 @@ where
             max_concurrent_shard_requests: None,
             pre_filter_shard_size: None,
             pretty: None,
+            request_timeout: None,
             rest_total_hits_as_int: None,
             search_type: None,
             source: None,
@@ -4440,6 +4705,7 @@ where
             max_concurrent_shard_requests: self.max_concurrent_shard_requests,
             pre_filter_shard_size: self.pre_filter_shard_size,
             pretty: self.pretty,
+            request_timeout: self.request_timeout,
             rest_total_hits_as_int: self.rest_total_hits_as_int,
             search_type: self.search_type,
             source: self.source,
@@ -4491,6 +4757,11 @@ where
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Indicates whether hits.total should be rendered as an integer or an object in the rest search response"]
     pub fn rest_total_hits_as_int(mut self, rest_total_hits_as_int: bool) -> Self {
         self.rest_total_hits_as_int = Some(rest_total_hits_as_int);
@@ -4519,6 +4790,7 @@ where
             None => Method::Get,
         };
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -4570,10 +4842,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("msearch")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Msearch API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Msearch Template API"]
@@ -4630,6 +4908,7 @@ pub struct MsearchTemplate<'a, 'b, B> {
     human: Option<bool>,
     max_concurrent_searches: Option<i64>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     rest_total_hits_as_int: Option<bool>,
     search_type: Option<SearchType>,
     source: Option<&'b str>,
@@ -4653,6 +4932,7 @@ where
             human: None,
             max_concurrent_searches: None,
             pretty: None,
+            request_timeout: None,
             rest_total_hits_as_int: None,
             search_type: None,
             source: None,
@@ -4675,6 +4955,7 @@ where
             human: self.human,
             max_concurrent_searches: self.max_concurrent_searches,
             pretty: self.pretty,
+            request_timeout: self.request_timeout,
             rest_total_hits_as_int: self.rest_total_hits_as_int,
             search_type: self.search_type,
             source: self.source,
@@ -4716,6 +4997,11 @@ where
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Indicates whether hits.total should be rendered as an integer or an object in the rest search response"]
     pub fn rest_total_hits_as_int(mut self, rest_total_hits_as_int: bool) -> Self {
         self.rest_total_hits_as_int = Some(rest_total_hits_as_int);
@@ -4744,6 +5030,7 @@ where
             None => Method::Get,
         };
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -4789,10 +5076,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("msearch_template")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Msearch Template API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Mtermvectors API"]
@@ -4852,6 +5145,7 @@ pub struct Mtermvectors<'a, 'b, B> {
     preference: Option<&'b str>,
     pretty: Option<bool>,
     realtime: Option<bool>,
+    request_timeout: Option<Duration>,
     routing: Option<&'b str>,
     source: Option<&'b str>,
     term_statistics: Option<bool>,
@@ -4882,6 +5176,7 @@ where
             preference: None,
             pretty: None,
             realtime: None,
+            request_timeout: None,
             routing: None,
             source: None,
             term_statistics: None,
@@ -4911,6 +5206,7 @@ where
             preference: self.preference,
             pretty: self.pretty,
             realtime: self.realtime,
+            request_timeout: self.request_timeout,
             routing: self.routing,
             source: self.source,
             term_statistics: self.term_statistics,
@@ -4983,6 +5279,11 @@ where
         self.realtime = Some(realtime);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Specific routing value. Applies to all returned documents unless otherwise specified in body \"params\" or \"docs\"."]
     pub fn routing(mut self, routing: &'b str) -> Self {
         self.routing = Some(routing);
@@ -5016,6 +5317,7 @@ where
             None => Method::Get,
         };
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -5082,10 +5384,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("mtermvectors")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Mtermvectors API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Ping API"]
@@ -5111,6 +5419,7 @@ pub struct Ping<'a, 'b> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
 }
 impl<'a, 'b> Ping<'a, 'b> {
@@ -5125,6 +5434,7 @@ impl<'a, 'b> Ping<'a, 'b> {
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             source: None,
         }
     }
@@ -5153,6 +5463,11 @@ impl<'a, 'b> Ping<'a, 'b> {
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -5163,6 +5478,7 @@ impl<'a, 'b> Ping<'a, 'b> {
         let path = self.parts.url();
         let method = Method::Head;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -5193,10 +5509,16 @@ impl<'a, 'b> Ping<'a, 'b> {
         let body = Option::<()>::None;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("ping")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Ping API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Put Script API"]
@@ -5245,6 +5567,7 @@ pub struct PutScript<'a, 'b, B> {
     human: Option<bool>,
     master_timeout: Option<&'b str>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
     timeout: Option<&'b str>,
 }
@@ -5266,6 +5589,7 @@ where
             human: None,
             master_timeout: None,
             pretty: None,
+            request_timeout: None,
             source: None,
             timeout: None,
         }
@@ -5286,6 +5610,7 @@ where
             human: self.human,
             master_timeout: self.master_timeout,
             pretty: self.pretty,
+            request_timeout: self.request_timeout,
             source: self.source,
             timeout: self.timeout,
         }
@@ -5325,6 +5650,11 @@ where
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -5340,6 +5670,7 @@ where
         let path = self.parts.url();
         let method = Method::Put;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -5379,10 +5710,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("put_script")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Put Script API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Reindex API"]
@@ -5411,6 +5748,7 @@ pub struct Reindex<'a, 'b, B> {
     max_docs: Option<i64>,
     pretty: Option<bool>,
     refresh: Option<bool>,
+    request_timeout: Option<Duration>,
     requests_per_second: Option<i64>,
     scroll: Option<&'b str>,
     slices: Option<Slices>,
@@ -5437,6 +5775,7 @@ where
             max_docs: None,
             pretty: None,
             refresh: None,
+            request_timeout: None,
             requests_per_second: None,
             scroll: None,
             slices: None,
@@ -5462,6 +5801,7 @@ where
             max_docs: self.max_docs,
             pretty: self.pretty,
             refresh: self.refresh,
+            request_timeout: self.request_timeout,
             requests_per_second: self.requests_per_second,
             scroll: self.scroll,
             slices: self.slices,
@@ -5506,6 +5846,11 @@ where
         self.refresh = Some(refresh);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The throttle to set on this request in sub-requests per second. -1 means no throttle."]
     pub fn requests_per_second(mut self, requests_per_second: i64) -> Self {
         self.requests_per_second = Some(requests_per_second);
@@ -5546,6 +5891,7 @@ where
         let path = self.parts.url();
         let method = Method::Post;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -5600,10 +5946,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("reindex")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Reindex API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Reindex Rethrottle API"]
@@ -5638,6 +5990,7 @@ pub struct ReindexRethrottle<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     requests_per_second: Option<i64>,
     source: Option<&'b str>,
 }
@@ -5657,6 +6010,7 @@ where
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             requests_per_second: None,
             source: None,
         }
@@ -5675,6 +6029,7 @@ where
             headers: self.headers,
             human: self.human,
             pretty: self.pretty,
+            request_timeout: self.request_timeout,
             requests_per_second: self.requests_per_second,
             source: self.source,
         }
@@ -5704,6 +6059,11 @@ where
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The throttle to set on this request in floating sub-requests per second. -1 means set no throttle."]
     pub fn requests_per_second(mut self, requests_per_second: i64) -> Self {
         self.requests_per_second = Some(requests_per_second);
@@ -5719,6 +6079,7 @@ where
         let path = self.parts.url();
         let method = Method::Post;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -5752,10 +6113,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("reindex_rethrottle")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Reindex Rethrottle API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Render Search Template API"]
@@ -5791,6 +6158,7 @@ pub struct RenderSearchTemplate<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     source: Option<&'b str>,
 }
 impl<'a, 'b, B> RenderSearchTemplate<'a, 'b, B>
@@ -5809,6 +6177,7 @@ where
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             source: None,
         }
     }
@@ -5826,6 +6195,7 @@ where
             headers: self.headers,
             human: self.human,
             pretty: self.pretty,
+            request_timeout: self.request_timeout,
             source: self.source,
         }
     }
@@ -5854,6 +6224,11 @@ where
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
     pub fn source(mut self, source: &'b str) -> Self {
         self.source = Some(source);
@@ -5867,6 +6242,7 @@ where
             None => Method::Get,
         };
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -5897,10 +6273,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("render_search_template")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Render Search Template API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Scroll API"]
@@ -5937,6 +6319,7 @@ pub struct Scroll<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     rest_total_hits_as_int: Option<bool>,
     scroll: Option<&'b str>,
     scroll_id: Option<&'b str>,
@@ -5958,6 +6341,7 @@ where
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             rest_total_hits_as_int: None,
             scroll: None,
             scroll_id: None,
@@ -5978,6 +6362,7 @@ where
             headers: self.headers,
             human: self.human,
             pretty: self.pretty,
+            request_timeout: self.request_timeout,
             rest_total_hits_as_int: self.rest_total_hits_as_int,
             scroll: self.scroll,
             scroll_id: self.scroll_id,
@@ -6009,6 +6394,11 @@ where
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Indicates whether hits.total should be rendered as an integer or an object in the rest search response"]
     pub fn rest_total_hits_as_int(mut self, rest_total_hits_as_int: bool) -> Self {
         self.rest_total_hits_as_int = Some(rest_total_hits_as_int);
@@ -6037,6 +6427,7 @@ where
             None => Method::Get,
         };
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -6076,10 +6467,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("scroll")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Scroll API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Search API"]
@@ -6157,6 +6554,7 @@ pub struct Search<'a, 'b, B> {
     pretty: Option<bool>,
     q: Option<&'b str>,
     request_cache: Option<bool>,
+    request_timeout: Option<Duration>,
     rest_total_hits_as_int: Option<bool>,
     routing: Option<&'b [&'b str]>,
     scroll: Option<&'b str>,
@@ -6217,6 +6615,7 @@ where
             pretty: None,
             q: None,
             request_cache: None,
+            request_timeout: None,
             rest_total_hits_as_int: None,
             routing: None,
             scroll: None,
@@ -6316,6 +6715,7 @@ where
             pretty: self.pretty,
             q: self.q,
             request_cache: self.request_cache,
+            request_timeout: self.request_timeout,
             rest_total_hits_as_int: self.rest_total_hits_as_int,
             routing: self.routing,
             scroll: self.scroll,
@@ -6438,6 +6838,11 @@ where
         self.request_cache = Some(request_cache);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Indicates whether hits.total should be rendered as an integer or an object in the rest search response"]
     pub fn rest_total_hits_as_int(mut self, rest_total_hits_as_int: bool) -> Self {
         self.rest_total_hits_as_int = Some(rest_total_hits_as_int);
@@ -6546,6 +6951,7 @@ where
             None => Method::Get,
         };
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -6720,10 +7126,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("search")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Search API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Search Shards API"]
@@ -6767,6 +7179,7 @@ pub struct SearchShards<'a, 'b, B> {
     local: Option<bool>,
     preference: Option<&'b str>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     routing: Option<&'b str>,
     source: Option<&'b str>,
 }
@@ -6791,6 +7204,7 @@ where
             local: None,
             preference: None,
             pretty: None,
+            request_timeout: None,
             routing: None,
             source: None,
         }
@@ -6819,6 +7233,7 @@ where
             local: self.local,
             preference: self.preference,
             pretty: self.pretty,
+            request_timeout: self.request_timeout,
             routing: self.routing,
             source: self.source,
         }
@@ -6868,6 +7283,11 @@ where
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Specific routing value"]
     pub fn routing(mut self, routing: &'b str) -> Self {
         self.routing = Some(routing);
@@ -6886,6 +7306,7 @@ where
             None => Method::Get,
         };
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -6934,10 +7355,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("search_shards")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Search Shards API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Search Template API"]
@@ -7000,6 +7427,7 @@ pub struct SearchTemplate<'a, 'b, B> {
     preference: Option<&'b str>,
     pretty: Option<bool>,
     profile: Option<bool>,
+    request_timeout: Option<Duration>,
     rest_total_hits_as_int: Option<bool>,
     routing: Option<&'b [&'b str]>,
     scroll: Option<&'b str>,
@@ -7031,6 +7459,7 @@ where
             preference: None,
             pretty: None,
             profile: None,
+            request_timeout: None,
             rest_total_hits_as_int: None,
             routing: None,
             scroll: None,
@@ -7066,6 +7495,7 @@ where
             preference: self.preference,
             pretty: self.pretty,
             profile: self.profile,
+            request_timeout: self.request_timeout,
             rest_total_hits_as_int: self.rest_total_hits_as_int,
             routing: self.routing,
             scroll: self.scroll,
@@ -7134,6 +7564,11 @@ where
         self.profile = Some(profile);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Indicates whether hits.total should be rendered as an integer or an object in the rest search response"]
     pub fn rest_total_hits_as_int(mut self, rest_total_hits_as_int: bool) -> Self {
         self.rest_total_hits_as_int = Some(rest_total_hits_as_int);
@@ -7172,6 +7607,7 @@ where
             None => Method::Get,
         };
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -7244,10 +7680,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("search_template")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Search Template API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Termvectors API"]
@@ -7335,6 +7777,7 @@ pub struct Termvectors<'a, 'b, B> {
     preference: Option<&'b str>,
     pretty: Option<bool>,
     realtime: Option<bool>,
+    request_timeout: Option<Duration>,
     routing: Option<&'b str>,
     source: Option<&'b str>,
     term_statistics: Option<bool>,
@@ -7364,6 +7807,7 @@ where
             preference: None,
             pretty: None,
             realtime: None,
+            request_timeout: None,
             routing: None,
             source: None,
             term_statistics: None,
@@ -7392,6 +7836,7 @@ where
             preference: self.preference,
             pretty: self.pretty,
             realtime: self.realtime,
+            request_timeout: self.request_timeout,
             routing: self.routing,
             source: self.source,
             term_statistics: self.term_statistics,
@@ -7459,6 +7904,11 @@ where
         self.realtime = Some(realtime);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Specific routing value."]
     pub fn routing(mut self, routing: &'b str) -> Self {
         self.routing = Some(routing);
@@ -7492,6 +7942,7 @@ where
             None => Method::Get,
         };
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -7555,10 +8006,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("termvectors")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Termvectors API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Update API"]
@@ -7621,6 +8078,7 @@ pub struct Update<'a, 'b, B> {
     lang: Option<&'b str>,
     pretty: Option<bool>,
     refresh: Option<Refresh>,
+    request_timeout: Option<Duration>,
     retry_on_conflict: Option<i64>,
     routing: Option<&'b str>,
     source: Option<&'b str>,
@@ -7650,6 +8108,7 @@ where
             lang: None,
             pretty: None,
             refresh: None,
+            request_timeout: None,
             retry_on_conflict: None,
             routing: None,
             source: None,
@@ -7693,6 +8152,7 @@ where
             lang: self.lang,
             pretty: self.pretty,
             refresh: self.refresh,
+            request_timeout: self.request_timeout,
             retry_on_conflict: self.retry_on_conflict,
             routing: self.routing,
             source: self.source,
@@ -7745,6 +8205,11 @@ where
         self.refresh = Some(refresh);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "Specify how many times should the operation be retried when a conflict occurs (default: 0)"]
     pub fn retry_on_conflict(mut self, retry_on_conflict: i64) -> Self {
         self.retry_on_conflict = Some(retry_on_conflict);
@@ -7775,6 +8240,7 @@ where
         let path = self.parts.url();
         let method = Method::Post;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -7847,10 +8313,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("update")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Update API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Update By Query API"]
@@ -7921,6 +8393,7 @@ pub struct UpdateByQuery<'a, 'b, B> {
     q: Option<&'b str>,
     refresh: Option<bool>,
     request_cache: Option<bool>,
+    request_timeout: Option<Duration>,
     requests_per_second: Option<i64>,
     routing: Option<&'b [&'b str]>,
     scroll: Option<&'b str>,
@@ -7974,6 +8447,7 @@ where
             q: None,
             refresh: None,
             request_cache: None,
+            request_timeout: None,
             requests_per_second: None,
             routing: None,
             scroll: None,
@@ -8056,6 +8530,7 @@ where
             q: self.q,
             refresh: self.refresh,
             request_cache: self.request_cache,
+            request_timeout: self.request_timeout,
             requests_per_second: self.requests_per_second,
             routing: self.routing,
             scroll: self.scroll,
@@ -8165,6 +8640,11 @@ where
         self.request_cache = Some(request_cache);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The throttle to set on this request in sub-requests per second. -1 means no throttle."]
     pub fn requests_per_second(mut self, requests_per_second: i64) -> Self {
         self.requests_per_second = Some(requests_per_second);
@@ -8255,6 +8735,7 @@ where
         let path = self.parts.url();
         let method = Method::Post;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -8402,10 +8883,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("update_by_query")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Update By Query API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 #[derive(Debug, Clone, PartialEq)]
 #[doc = "API parts for the Update By Query Rethrottle API"]
@@ -8440,6 +8927,7 @@ pub struct UpdateByQueryRethrottle<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     pretty: Option<bool>,
+    request_timeout: Option<Duration>,
     requests_per_second: Option<i64>,
     source: Option<&'b str>,
 }
@@ -8459,6 +8947,7 @@ where
             filter_path: None,
             human: None,
             pretty: None,
+            request_timeout: None,
             requests_per_second: None,
             source: None,
         }
@@ -8477,6 +8966,7 @@ where
             headers: self.headers,
             human: self.human,
             pretty: self.pretty,
+            request_timeout: self.request_timeout,
             requests_per_second: self.requests_per_second,
             source: self.source,
         }
@@ -8506,6 +8996,11 @@ where
         self.pretty = Some(pretty);
         self
     }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
     #[doc = "The throttle to set on this request in floating sub-requests per second. -1 means set no throttle."]
     pub fn requests_per_second(mut self, requests_per_second: i64) -> Self {
         self.requests_per_second = Some(requests_per_second);
@@ -8521,6 +9016,7 @@ where
         let path = self.parts.url();
         let method = Method::Post;
         let headers = self.headers;
+        let timeout = self.request_timeout;
         let query_string = {
             #[serde_with::skip_serializing_none]
             #[derive(Serialize)]
@@ -8554,10 +9050,16 @@ where
         let body = self.body;
         let response = self
             .client
-            .send(method, &path, headers, query_string.as_ref(), body)
+            .endpoint("update_by_query_rethrottle")
+            .send(method, &path, headers, query_string.as_ref(), body, timeout)
             .await?;
         Ok(response)
     }
+    #[cfg(feature = "blocking")]
+    #[doc = "Creates a synchronous call to the Update By Query Rethrottle API that blocks until the response is received. Requires the `blocking` feature"]
+    pub fn send_blocking(self) -> Result<crate::blocking::Response, Error> {
+        crate::blocking::block_on(self.send()).map(crate::blocking::Response::new)
+    }
 }
 impl Elasticsearch {
     #[doc = "[Bulk API](https://www.elastic.co/guide/en/elasticsearch/reference/7.7/docs-bulk.html)\n\nAllows to perform multiple index/update/delete operations in a single request."]
@@ -0,0 +1,442 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! Record and replay components for testing code that uses the client
+//!
+//! Requires the `test-util` feature. A [Cassette] records the requests sent to Elasticsearch
+//! and the responses received to a JSON file, and replays the responses from the file in a
+//! later run, without needing a running Elasticsearch cluster. Fixtures can be captured once
+//! against a local node, and integration tests then run offline.
+//!
+//! Recorded requests are matched on method, path, query string and body. The query string is
+//! matched irrespective of the order of its parameters, and JSON and NDJSON bodies are
+//! matched irrespective of whitespace and the order of object keys. Bodies that are not
+//! valid UTF-8 are stored base64 encoded, with a `body_encoding` of `base64`.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use elasticsearch::{
+//! #     Elasticsearch,
+//! #     http::{cassette::Cassette, transport::{SingleNodeConnectionPool, TransportBuilder}},
+//! # };
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! // records when the file does not exist, and replays from it when it does
+//! let cassette = Cassette::new("tests/fixtures/ping.json")?;
+//! let transport = TransportBuilder::new(SingleNodeConnectionPool::default())
+//!     .cassette(cassette.clone())
+//!     .build()?;
+//! let client = Elasticsearch::new(transport);
+//!
+//! let response = client.ping().send().await?;
+//!
+//! cassette.save()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    error::Error,
+    http::{
+        client::{BoxFuture, HttpClient, HttpError, HttpResponse, ReqwestClient},
+        headers::{HeaderMap, HeaderName, HeaderValue, CONTENT_ENCODING},
+        request::Request,
+        Method, StatusCode,
+    },
+};
+use flate2::read::GzDecoder;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    fs,
+    io::Read,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+use url::{form_urlencoded, Url};
+
+/// Records HTTP interactions with Elasticsearch to a file, and replays them from it.
+///
+/// A [Cassette] is passed to
+/// [TransportBuilder::cassette](crate::http::transport::TransportBuilder::cassette), and
+/// records requests sent with the [HttpClient] of the transport, so that the TLS, proxy and
+/// timeout options configured on the builder apply. A [Cassette] is also a [HttpClient] that
+/// can be passed to
+/// [TransportBuilder::http_client](crate::http::transport::TransportBuilder::http_client),
+/// in which case it records requests sent with the client it was created with.
+/// Clones of a [Cassette] share the same interactions.
+#[derive(Debug, Clone)]
+pub struct Cassette {
+    path: PathBuf,
+    mode: Mode,
+    interactions: Arc<Mutex<Vec<Interaction>>>,
+}
+
+#[derive(Debug, Clone)]
+enum Mode {
+    Record(Arc<dyn HttpClient>),
+    Replay,
+}
+
+/// A recorded request and the response received for it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+    request: RecordedRequest,
+    response: RecordedResponse,
+    #[serde(skip)]
+    played: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct RecordedRequest {
+    method: String,
+    path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    query: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body_encoding: Option<BodyEncoding>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordedResponse {
+    status: u16,
+    #[serde(default)]
+    headers: Vec<(String, String)>,
+    #[serde(default)]
+    body: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body_encoding: Option<BodyEncoding>,
+}
+
+/// The encoding of a recorded body that is not valid UTF-8
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum BodyEncoding {
+    Base64,
+}
+
+impl Cassette {
+    /// Creates a cassette that replays the interactions in the file when it exists, and
+    /// otherwise records interactions, as with [Cassette::record]
+    pub fn new<P>(path: P) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+    {
+        if path.as_ref().exists() {
+            Self::replay(path)
+        } else {
+            Ok(Self::record(path))
+        }
+    }
+
+    /// Creates a cassette that records interactions to save to the file, sent with the client
+    /// of the transport when set with
+    /// [TransportBuilder::cassette](crate::http::transport::TransportBuilder::cassette), or
+    /// with the default [ReqwestClient] when used as the client of the transport
+    pub fn record<P>(path: P) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        Self::record_with(path, ReqwestClient::default())
+    }
+
+    /// Creates a cassette that records interactions sent with the given client, to save
+    /// to the file
+    pub fn record_with<P, C>(path: P, client: C) -> Self
+    where
+        P: AsRef<Path>,
+        C: HttpClient + 'static,
+    {
+        Self {
+            path: path.as_ref().into(),
+            mode: Mode::Record(Arc::new(client)),
+            interactions: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Creates a cassette that replays the interactions recorded in the file
+    pub fn replay<P>(path: P) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+    {
+        let contents = fs::read(path.as_ref())?;
+        let interactions: Vec<Interaction> = serde_json::from_slice(&contents)?;
+        Ok(Self {
+            path: path.as_ref().into(),
+            mode: Mode::Replay,
+            interactions: Arc::new(Mutex::new(interactions)),
+        })
+    }
+
+    /// A clone of the cassette that records requests sent with the given client,
+    /// sharing the interactions of the cassette
+    pub(crate) fn wrap(&self, client: Arc<dyn HttpClient>) -> Self {
+        let mode = match self.mode {
+            Mode::Record(_) => Mode::Record(client),
+            Mode::Replay => Mode::Replay,
+        };
+        Self {
+            path: self.path.clone(),
+            mode,
+            interactions: self.interactions.clone(),
+        }
+    }
+
+    /// Whether the cassette is recording interactions
+    pub fn is_recording(&self) -> bool {
+        match self.mode {
+            Mode::Record(_) => true,
+            Mode::Replay => false,
+        }
+    }
+
+    /// Saves the recorded interactions to the file, creating any missing parent directories.
+    /// Does nothing when the cassette is replaying.
+    pub fn save(&self) -> Result<(), Error> {
+        if !self.is_recording() {
+            return Ok(());
+        }
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let interactions = self.interactions.lock().unwrap();
+        let contents = serde_json::to_vec_pretty(&*interactions)?;
+        fs::write(&self.path, contents)?;
+        Ok(())
+    }
+
+    async fn record_send(
+        &self,
+        client: &dyn HttpClient,
+        request: Request,
+    ) -> Result<HttpResponse, HttpError> {
+        let recorded_request = RecordedRequest::new(&request);
+        let response = client.send(request).await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let url = response.url().clone();
+        let body = response.bytes().await?;
+
+        let (recorded_body, body_encoding) = encode_body(&body);
+        let recorded_response = RecordedResponse {
+            status: status.as_u16(),
+            headers: headers
+                .iter()
+                .filter_map(|(k, v)| v.to_str().ok().map(|v| (k.to_string(), v.to_string())))
+                .collect(),
+            body: recorded_body,
+            body_encoding,
+        };
+        self.interactions.lock().unwrap().push(Interaction {
+            request: recorded_request,
+            response: recorded_response,
+            played: false,
+        });
+
+        Ok(HttpResponse::from_bytes(status, headers, url, body))
+    }
+
+    fn replay_send(&self, request: Request) -> Result<HttpResponse, HttpError> {
+        let recorded_request = RecordedRequest::new(&request);
+        let mut interactions = self.interactions.lock().unwrap();
+
+        // prefer an interaction that has not been played, to replay repeated requests in order
+        let position = interactions
+            .iter()
+            .position(|i| !i.played && i.request == recorded_request)
+            .or_else(|| {
+                interactions
+                    .iter()
+                    .rposition(|i| i.request == recorded_request)
+            });
+
+        match position {
+            Some(p) => {
+                let interaction = &mut interactions[p];
+                interaction.played = true;
+                interaction.response.to_http_response(request.url().clone())
+            }
+            None => Err(HttpError::other(format!(
+                "no recorded interaction for {} {}{}",
+                recorded_request.method,
+                recorded_request.path,
+                recorded_request
+                    .query
+                    .as_ref()
+                    .map(|q| format!("?{}", q))
+                    .unwrap_or_default()
+            ))),
+        }
+    }
+}
+
+impl HttpClient for Cassette {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<HttpResponse, HttpError>> {
+        match &self.mode {
+            Mode::Record(client) => Box::pin(self.record_send(client.as_ref(), request)),
+            Mode::Replay => {
+                let response = self.replay_send(request);
+                Box::pin(async move { response })
+            }
+        }
+    }
+}
+
+impl RecordedRequest {
+    fn new(request: &Request) -> Self {
+        let method = match request.method() {
+            Method::Get => "GET",
+            Method::Put => "PUT",
+            Method::Post => "POST",
+            Method::Delete => "DELETE",
+            Method::Head => "HEAD",
+        };
+
+        let gzipped = matches!(request.headers().get(CONTENT_ENCODING), Some(v) if v == "gzip");
+
+        let (body, body_encoding) = match request.body() {
+            Some(b) => {
+                let (body, encoding) = normalize_body(b, gzipped);
+                (Some(body), encoding)
+            }
+            None => (None, None),
+        };
+
+        Self {
+            method: method.into(),
+            path: request.url().path().into(),
+            query: normalize_query(request.url()),
+            body,
+            body_encoding,
+        }
+    }
+}
+
+impl RecordedResponse {
+    fn to_http_response(&self, url: Url) -> Result<HttpResponse, HttpError> {
+        let status = StatusCode::from_u16(self.status).map_err(HttpError::other)?;
+        let mut headers = HeaderMap::with_capacity(self.headers.len());
+        for (k, v) in &self.headers {
+            let name = HeaderName::from_bytes(k.as_bytes()).map_err(HttpError::other)?;
+            let value = HeaderValue::from_str(v).map_err(HttpError::other)?;
+            headers.append(name, value);
+        }
+        let body = match self.body_encoding {
+            Some(BodyEncoding::Base64) => base64::decode(&self.body).map_err(HttpError::other)?,
+            None => self.body.clone().into_bytes(),
+        };
+        Ok(HttpResponse::from_bytes(status, headers, url, body))
+    }
+}
+
+/// The query string with its parameters sorted
+fn normalize_query(url: &Url) -> Option<String> {
+    let mut pairs: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+    if pairs.is_empty() {
+        return None;
+    }
+    pairs.sort();
+    Some(
+        form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(pairs)
+            .finish(),
+    )
+}
+
+/// The body as text, or base64 encoded when it is not valid UTF-8
+fn encode_body(bytes: &[u8]) -> (String, Option<BodyEncoding>) {
+    match std::str::from_utf8(bytes) {
+        Ok(body) => (body.to_string(), None),
+        Err(_) => (base64::encode(bytes), Some(BodyEncoding::Base64)),
+    }
+}
+
+/// The body with each JSON document re-serialized, which sorts object keys and removes
+/// whitespace. A body that is not JSON or NDJSON is kept as is.
+fn normalize_body(bytes: &[u8], gzipped: bool) -> (String, Option<BodyEncoding>) {
+    let mut decompressed = Vec::new();
+    let bytes = if gzipped && GzDecoder::new(bytes).read_to_end(&mut decompressed).is_ok() {
+        &decompressed[..]
+    } else {
+        bytes
+    };
+
+    let body = match encode_body(bytes) {
+        (body, None) => body,
+        encoded => return encoded,
+    };
+    let lines: Option<Vec<String>> = body
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| serde_json::from_str::<Value>(l).ok().map(|v| v.to_string()))
+        .collect();
+
+    match lines {
+        Some(lines) if !lines.is_empty() => (lines.join("\n"), None),
+        _ => (body, None),
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{normalize_body, normalize_query, BodyEncoding};
+    use url::Url;
+
+    #[test]
+    fn normalize_query_sorts_parameters() {
+        let url = Url::parse("http://localhost:9200/_search?size=10&from=5").unwrap();
+        assert_eq!(normalize_query(&url), Some("from=5&size=10".into()));
+
+        let url = Url::parse("http://localhost:9200/_search").unwrap();
+        assert_eq!(normalize_query(&url), None);
+    }
+
+    #[test]
+    fn normalize_json_body() {
+        let a = normalize_body(br#"{ "b": 1, "a": { "d": true, "c": null } }"#, false);
+        let b = normalize_body(br#"{"a":{"c":null,"d":true},"b":1}"#, false);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn normalize_ndjson_body() {
+        let a = normalize_body(b"{ \"index\": {} }\n{ \"b\": 1, \"a\": 2 }\n", false);
+        let b = normalize_body(b"{\"index\":{}}\n{\"a\":2,\"b\":1}\n", false);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn normalize_text_body() {
+        assert_eq!(
+            normalize_body(b"not json", false),
+            ("not json".to_string(), None)
+        );
+    }
+
+    #[test]
+    fn normalize_binary_body() {
+        assert_eq!(
+            normalize_body(b"\xff\x00\xfe", false),
+            ("/wD+".to_string(), Some(BodyEncoding::Base64))
+        );
+    }
+}
@@ -0,0 +1,310 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! HTTP client components
+//!
+//! A [Transport](crate::http::transport::Transport) sends requests to Elasticsearch through
+//! a [HttpClient]. By default, [ReqwestClient] is used, which sends requests with
+//! [`reqwest`](https://crates.io/crates/reqwest). A different HTTP stack, or an in-memory
+//! fake for tests, can be used by implementing [HttpClient] and passing it to
+//! [TransportBuilder::http_client](crate::http::transport::TransportBuilder::http_client).
+
+use crate::http::{headers::HeaderMap, request::Request, Method, StatusCode};
+use bytes::Bytes;
+use std::{error, fmt, fmt::Debug, future::Future, pin::Pin};
+use url::Url;
+
+/// An owned dynamically typed future that can be sent between threads
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// A client that sends HTTP requests to Elasticsearch nodes
+pub trait HttpClient: Debug + Send + Sync {
+    /// Sends a request, returning the response once its status and headers have been received.
+    ///
+    /// An implementation should respect the timeout of the request, if any.
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<HttpResponse, HttpError>>;
+}
+
+impl<C> HttpClient for std::sync::Arc<C>
+where
+    C: HttpClient + ?Sized,
+{
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<HttpResponse, HttpError>> {
+        (**self).send(request)
+    }
+}
+
+/// A HTTP response returned by a [HttpClient], whose body is read lazily
+pub struct HttpResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    url: Url,
+    body: BoxFuture<'static, Result<Bytes, HttpError>>,
+}
+
+impl HttpResponse {
+    /// Creates a new instance of a response, whose body is read when the future resolves
+    pub fn new<F>(status: StatusCode, headers: HeaderMap, url: Url, body: F) -> Self
+    where
+        F: Future<Output = Result<Bytes, HttpError>> + Send + 'static,
+    {
+        Self {
+            status,
+            headers,
+            url,
+            body: Box::pin(body),
+        }
+    }
+
+    /// Creates a new instance of a response with a body that has already been read
+    pub fn from_bytes<B>(status: StatusCode, headers: HeaderMap, url: Url, body: B) -> Self
+    where
+        B: Into<Bytes>,
+    {
+        let body = body.into();
+        Self::new(status, headers, url, async move { Ok(body) })
+    }
+
+    /// The HTTP status code of the response
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// The headers of the response
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// The URL of the response
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Asynchronously reads the response body
+    pub async fn bytes(self) -> Result<Bytes, HttpError> {
+        self.body.await
+    }
+}
+
+impl Debug for HttpResponse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("HttpResponse")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .field("url", &self.url)
+            .finish()
+    }
+}
+
+/// An error that occurred sending a request with a [HttpClient], or reading its response
+#[derive(Debug)]
+pub struct HttpError {
+    kind: HttpErrorKind,
+    source: Box<dyn error::Error + Send + Sync>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum HttpErrorKind {
+    Connect,
+    ConnectTimeout,
+    Timeout,
+    Other,
+}
+
+impl HttpError {
+    /// Creates an error for a failure to connect to a node
+    pub fn connect<E>(err: E) -> Self
+    where
+        E: Into<Box<dyn error::Error + Send + Sync>>,
+    {
+        Self {
+            kind: HttpErrorKind::Connect,
+            source: err.into(),
+        }
+    }
+
+    /// Creates an error for a request that did not complete within its timeout
+    pub fn timeout<E>(err: E) -> Self
+    where
+        E: Into<Box<dyn error::Error + Send + Sync>>,
+    {
+        Self {
+            kind: HttpErrorKind::Timeout,
+            source: err.into(),
+        }
+    }
+
+    /// Creates an error for a failure to connect to a node within the connect timeout
+    pub fn connect_timeout<E>(err: E) -> Self
+    where
+        E: Into<Box<dyn error::Error + Send + Sync>>,
+    {
+        Self {
+            kind: HttpErrorKind::ConnectTimeout,
+            source: err.into(),
+        }
+    }
+
+    /// Creates an error for any other failure
+    pub fn other<E>(err: E) -> Self
+    where
+        E: Into<Box<dyn error::Error + Send + Sync>>,
+    {
+        Self {
+            kind: HttpErrorKind::Other,
+            source: err.into(),
+        }
+    }
+
+    /// Whether the error occurred connecting to a node
+    pub fn is_connect(&self) -> bool {
+        matches!(
+            self.kind,
+            HttpErrorKind::Connect | HttpErrorKind::ConnectTimeout
+        )
+    }
+
+    /// Whether the error is a timeout, including a timeout connecting to a node
+    pub fn is_timeout(&self) -> bool {
+        matches!(
+            self.kind,
+            HttpErrorKind::Timeout | HttpErrorKind::ConnectTimeout
+        )
+    }
+}
+
+impl error::Error for HttpError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&*self.source)
+    }
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.source, f)
+    }
+}
+
+impl HttpError {
+    fn reqwest(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            if err.is_connect() {
+                Self::connect_timeout(err)
+            } else {
+                Self::timeout(err)
+            }
+        } else if err.is_connect() {
+            Self::connect(err)
+        } else {
+            Self::other(err)
+        }
+    }
+}
+
+/// A [HttpClient] that sends requests with [`reqwest`](https://crates.io/crates/reqwest).
+///
+/// This is the client used by default, built from the options configured on
+/// [TransportBuilder](crate::http::transport::TransportBuilder).
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestClient {
+    client: reqwest::Client,
+}
+
+impl ReqwestClient {
+    /// Creates a new instance of [ReqwestClient] that sends requests with the given client
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+
+    fn method(method: Method) -> reqwest::Method {
+        match method {
+            Method::Get => reqwest::Method::GET,
+            Method::Put => reqwest::Method::PUT,
+            Method::Post => reqwest::Method::POST,
+            Method::Delete => reqwest::Method::DELETE,
+            Method::Head => reqwest::Method::HEAD,
+        }
+    }
+}
+
+impl HttpClient for ReqwestClient {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<HttpResponse, HttpError>> {
+        let mut request_builder = self
+            .client
+            .request(Self::method(request.method()), request.url().clone())
+            .headers(request.headers().clone());
+
+        if let Some(b) = request.body() {
+            request_builder = request_builder.body(b.clone());
+        }
+
+        if let Some(t) = request.timeout() {
+            request_builder = request_builder.timeout(t);
+        }
+
+        Box::pin(async move {
+            let response = request_builder.send().await.map_err(HttpError::reqwest)?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            let url = response.url().clone();
+            let body = async move { response.bytes().await.map_err(HttpError::reqwest) };
+            Ok(HttpResponse::new(status, headers, url, body))
+        })
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::http::{
+        client::{HttpClient, ReqwestClient},
+        request::Request,
+        Method,
+    };
+    use std::{
+        net::{TcpListener, TcpStream},
+        time::Duration,
+    };
+    use url::Url;
+
+    #[tokio::test]
+    async fn connect_timeout_is_timeout() {
+        // a listener that never accepts. Once its backlog is full, connecting to it
+        // never completes, as it would with an unroutable address
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let mut backlog = Vec::new();
+        while let Ok(stream) = TcpStream::connect_timeout(&addr, Duration::from_millis(100)) {
+            backlog.push(stream);
+        }
+
+        let client = reqwest::Client::builder()
+            .connect_timeout(Duration::from_millis(1))
+            .build()
+            .unwrap();
+        let client = ReqwestClient::new(client);
+        let url = Url::parse(&format!("http://{}/", addr)).unwrap();
+        let err = client
+            .send(Request::new(Method::Get, url))
+            .await
+            .err()
+            .unwrap();
+
+        assert!(err.is_timeout());
+        assert!(err.is_connect());
+    }
+}
@@ -32,3 +32,11 @@ pub static DEFAULT_ACCEPT: &str = "application/json";
 /// The X-Opaque-Id header name, used to track certain calls, or associate
 /// certain tasks with a client that started them.
 pub static X_OPAQUE_ID: &str = "x-opaque-id";
+
+/// The traceparent header name, used to propagate the
+/// [W3C trace context](https://www.w3.org/TR/trace-context/) of a call.
+pub static TRACEPARENT: &str = "traceparent";
+
+/// The tracestate header name, used to propagate vendor specific
+/// [W3C trace context](https://www.w3.org/TR/trace-context/) of a call.
+pub static TRACESTATE: &str = "tracestate";
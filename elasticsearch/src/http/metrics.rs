@@ -0,0 +1,410 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! Client-side metrics components
+//!
+//! When enabled with [TransportBuilder::metrics](crate::http::transport::TransportBuilder::metrics),
+//! the [Transport](crate::http::transport::Transport) collects metrics for the requests it sends:
+//!
+//! - the number of requests sent to each node, per endpoint, status class and error kind
+//! - a histogram of the latency of API calls per endpoint, including any retries
+//! - a histogram of the size of bulk API request bodies
+//!
+//! A [MetricsSnapshot] of the metrics collected so far is returned by
+//! [Transport::metrics_snapshot](crate::http::transport::Transport::metrics_snapshot), which can be
+//! exported in the Prometheus text exposition format with [MetricsSnapshot::to_prometheus].
+
+use std::{collections::BTreeMap, fmt::Write, sync::Mutex, time::Duration};
+
+/// The upper bounds in seconds of the buckets of the API call latency histograms
+pub static LATENCY_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// The upper bounds in bytes of the buckets of the bulk request body size histogram
+pub static BULK_SIZE_BUCKETS: &[f64] = &[
+    1_024.0,
+    10_240.0,
+    102_400.0,
+    1_048_576.0,
+    5_242_880.0,
+    10_485_760.0,
+    52_428_800.0,
+    104_857_600.0,
+];
+
+/// The kind of error that occurred sending a request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ErrorKind {
+    /// Connecting to the node failed
+    Connection,
+    /// The request timed out
+    Timeout,
+    /// Any other error
+    Other,
+}
+
+impl ErrorKind {
+    /// The name of the error kind, used as a label value
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorKind::Connection => "connection",
+            ErrorKind::Timeout => "timeout",
+            ErrorKind::Other => "other",
+        }
+    }
+}
+
+/// The outcome of a request sent to a node
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Outcome {
+    /// A response was received with a status code in the class, e.g. `2` for `2xx`
+    Status(u16),
+    /// No response was received because of an error
+    Error(ErrorKind),
+}
+
+/// The labels by which requests are counted
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RequestLabels {
+    endpoint: String,
+    node: String,
+    outcome: Outcome,
+}
+
+impl RequestLabels {
+    /// The name of the API endpoint, such as `indices.create`
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// The URL of the node to which the request was sent
+    pub fn node(&self) -> &str {
+        &self.node
+    }
+
+    /// The outcome of the request
+    pub fn outcome(&self) -> Outcome {
+        self.outcome
+    }
+
+    /// The status class of the response, such as `2xx`, if a response was received
+    pub fn status_class(&self) -> Option<String> {
+        match self.outcome {
+            Outcome::Status(class) => Some(format!("{}xx", class)),
+            Outcome::Error(_) => None,
+        }
+    }
+
+    /// The kind of error, if no response was received
+    pub fn error_kind(&self) -> Option<ErrorKind> {
+        match self.outcome {
+            Outcome::Status(_) => None,
+            Outcome::Error(kind) => Some(kind),
+        }
+    }
+}
+
+/// A histogram of observed values, with fixed buckets
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    bounds: &'static [f64],
+    counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn new(bounds: &'static [f64]) -> Self {
+        Self {
+            bounds,
+            counts: vec![0; bounds.len()],
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    fn observe(&mut self, value: f64) {
+        if let Some(i) = self.bounds.iter().position(|b| value <= *b) {
+            self.counts[i] += 1;
+        }
+        self.sum += value;
+        self.count += 1;
+    }
+
+    /// The cumulative count of observations for each bucket, as pairs of the upper bound
+    /// of the bucket and the number of observations less than or equal to it. Observations
+    /// greater than the largest bound are counted only in [Histogram::count].
+    pub fn buckets(&self) -> Vec<(f64, u64)> {
+        let mut cumulative = 0;
+        self.bounds
+            .iter()
+            .zip(self.counts.iter())
+            .map(|(bound, count)| {
+                cumulative += count;
+                (*bound, cumulative)
+            })
+            .collect()
+    }
+
+    /// The sum of all observations
+    pub fn sum(&self) -> f64 {
+        self.sum
+    }
+
+    /// The number of observations
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+}
+
+/// A snapshot of the metrics collected by a transport
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricsSnapshot {
+    requests: BTreeMap<RequestLabels, u64>,
+    latency: BTreeMap<String, Histogram>,
+    bulk_size: Histogram,
+}
+
+impl Default for MetricsSnapshot {
+    fn default() -> Self {
+        Self {
+            requests: BTreeMap::new(),
+            latency: BTreeMap::new(),
+            bulk_size: Histogram::new(BULK_SIZE_BUCKETS),
+        }
+    }
+}
+
+impl MetricsSnapshot {
+    /// The number of requests sent, by their labels. Each attempt to send an API call
+    /// is a separate request.
+    pub fn requests(&self) -> impl Iterator<Item = (&RequestLabels, u64)> {
+        self.requests.iter().map(|(labels, count)| (labels, *count))
+    }
+
+    /// The total number of requests sent for the endpoint
+    pub fn request_count(&self, endpoint: &str) -> u64 {
+        self.requests
+            .iter()
+            .filter(|(labels, _)| labels.endpoint == endpoint)
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+    /// The latency histogram in seconds of the API calls to the endpoint, if any
+    pub fn latency(&self, endpoint: &str) -> Option<&Histogram> {
+        self.latency.get(endpoint)
+    }
+
+    /// The latency histograms in seconds of API calls, by endpoint
+    pub fn latencies(&self) -> impl Iterator<Item = (&str, &Histogram)> {
+        self.latency.iter().map(|(e, h)| (e.as_str(), h))
+    }
+
+    /// The histogram in bytes of the size of bulk API request bodies
+    pub fn bulk_size(&self) -> &Histogram {
+        &self.bulk_size
+    }
+
+    /// Formats the metrics in the
+    /// [Prometheus text exposition format](https://prometheus.io/docs/instrumenting/exposition_formats/)
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+
+        out.push_str(
+            "# HELP elasticsearch_client_requests_total Requests sent to Elasticsearch nodes.\n",
+        );
+        out.push_str("# TYPE elasticsearch_client_requests_total counter\n");
+        for (labels, count) in &self.requests {
+            let _ = writeln!(
+                out,
+                "elasticsearch_client_requests_total{{endpoint=\"{}\",node=\"{}\",status=\"{}\",error=\"{}\"}} {}",
+                escape(&labels.endpoint),
+                escape(&labels.node),
+                labels.status_class().unwrap_or_default(),
+                labels.error_kind().map(|k| k.as_str()).unwrap_or_default(),
+                count
+            );
+        }
+
+        out.push_str("# HELP elasticsearch_client_request_duration_seconds Latency of API calls, including retries.\n");
+        out.push_str("# TYPE elasticsearch_client_request_duration_seconds histogram\n");
+        for (endpoint, histogram) in &self.latency {
+            write_histogram(
+                &mut out,
+                "elasticsearch_client_request_duration_seconds",
+                &format!("endpoint=\"{}\"", escape(endpoint)),
+                histogram,
+            );
+        }
+
+        out.push_str("# HELP elasticsearch_client_bulk_request_size_bytes Size of bulk API request bodies.\n");
+        out.push_str("# TYPE elasticsearch_client_bulk_request_size_bytes histogram\n");
+        write_histogram(
+            &mut out,
+            "elasticsearch_client_bulk_request_size_bytes",
+            "",
+            &self.bulk_size,
+        );
+
+        out
+    }
+}
+
+fn write_histogram(out: &mut String, name: &str, labels: &str, histogram: &Histogram) {
+    let separator = if labels.is_empty() { "" } else { "," };
+    for (bound, count) in histogram.buckets() {
+        let _ = writeln!(
+            out,
+            "{}_bucket{{{}{}le=\"{}\"}} {}",
+            name, labels, separator, bound, count
+        );
+    }
+    let _ = writeln!(
+        out,
+        "{}_bucket{{{}{}le=\"+Inf\"}} {}",
+        name, labels, separator, histogram.count
+    );
+    let labels = if labels.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", labels)
+    };
+    let _ = writeln!(out, "{}_sum{} {}", name, labels, histogram.sum);
+    let _ = writeln!(out, "{}_count{} {}", name, labels, histogram.count);
+}
+
+/// Escapes a label value
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Collects metrics for the requests sent by a transport
+#[derive(Debug, Default)]
+pub(crate) struct Metrics {
+    snapshot: Mutex<MetricsSnapshot>,
+}
+
+impl Metrics {
+    /// Counts a request sent to a node
+    pub(crate) fn request(&self, endpoint: &str, node: &str, outcome: Outcome) {
+        let labels = RequestLabels {
+            endpoint: endpoint.into(),
+            node: node.into(),
+            outcome,
+        };
+        let mut snapshot = self.snapshot.lock().unwrap();
+        *snapshot.requests.entry(labels).or_insert(0) += 1;
+    }
+
+    /// Observes the latency of an API call to an endpoint
+    pub(crate) fn latency(&self, endpoint: &str, latency: Duration) {
+        let mut snapshot = self.snapshot.lock().unwrap();
+        snapshot
+            .latency
+            .entry(endpoint.into())
+            .or_insert_with(|| Histogram::new(LATENCY_BUCKETS))
+            .observe(latency.as_secs_f64());
+    }
+
+    /// Observes the size of a bulk API request body
+    pub(crate) fn bulk_size(&self, size: usize) {
+        let mut snapshot = self.snapshot.lock().unwrap();
+        snapshot.bulk_size.observe(size as f64);
+    }
+
+    pub(crate) fn snapshot(&self) -> MetricsSnapshot {
+        self.snapshot.lock().unwrap().clone()
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{ErrorKind, Metrics, Outcome};
+    use std::time::Duration;
+
+    #[test]
+    fn counts_requests_by_labels() {
+        let metrics = Metrics::default();
+        metrics.request("search", "http://a:9200/", Outcome::Status(2));
+        metrics.request("search", "http://a:9200/", Outcome::Status(2));
+        metrics.request(
+            "search",
+            "http://b:9200/",
+            Outcome::Error(ErrorKind::Connection),
+        );
+        metrics.request("ping", "http://a:9200/", Outcome::Status(5));
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.request_count("search"), 3);
+        assert_eq!(snapshot.request_count("ping"), 1);
+        assert_eq!(snapshot.requests().count(), 3);
+    }
+
+    #[test]
+    fn histogram_buckets_are_cumulative() {
+        let metrics = Metrics::default();
+        metrics.latency("search", Duration::from_millis(3));
+        metrics.latency("search", Duration::from_millis(30));
+        metrics.latency("search", Duration::from_secs(20));
+
+        let snapshot = metrics.snapshot();
+        let histogram = snapshot.latency("search").unwrap();
+        assert_eq!(histogram.count(), 3);
+        let buckets = histogram.buckets();
+        assert_eq!(buckets[0], (0.005, 1));
+        assert_eq!(buckets[3], (0.05, 2));
+        assert_eq!(buckets.last().unwrap(), &(10.0, 2));
+    }
+
+    #[test]
+    fn prometheus_text_format() {
+        let metrics = Metrics::default();
+        metrics.request("search", "http://a:9200/", Outcome::Status(2));
+        metrics.request(
+            "search",
+            "http://a:9200/",
+            Outcome::Error(ErrorKind::Timeout),
+        );
+        metrics.latency("search", Duration::from_millis(20));
+        metrics.bulk_size(2048);
+
+        let text = metrics.snapshot().to_prometheus();
+        assert!(text.contains("# TYPE elasticsearch_client_requests_total counter\n"));
+        assert!(text.contains(
+            "elasticsearch_client_requests_total{endpoint=\"search\",node=\"http://a:9200/\",status=\"2xx\",error=\"\"} 1\n"
+        ));
+        assert!(text.contains(
+            "elasticsearch_client_requests_total{endpoint=\"search\",node=\"http://a:9200/\",status=\"\",error=\"timeout\"} 1\n"
+        ));
+        assert!(text.contains(
+            "elasticsearch_client_request_duration_seconds_bucket{endpoint=\"search\",le=\"0.025\"} 1\n"
+        ));
+        assert!(text.contains(
+            "elasticsearch_client_request_duration_seconds_count{endpoint=\"search\"} 1\n"
+        ));
+        assert!(
+            text.contains("elasticsearch_client_bulk_request_size_bytes_bucket{le=\"10240\"} 1\n")
+        );
+        assert!(text.contains("elasticsearch_client_bulk_request_size_bytes_sum 2048\n"));
+    }
+}
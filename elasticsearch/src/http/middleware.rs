@@ -0,0 +1,82 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! Middleware components
+//!
+//! A [Middleware] plugs behaviour into each request that the
+//! [Transport](crate::http::transport::Transport) sends to Elasticsearch, such as request
+//! signing, adding headers, auditing and inspecting responses. Middleware is registered with
+//! [TransportBuilder::middleware](crate::http::transport::TransportBuilder::middleware).
+
+use crate::{
+    error::Error,
+    http::{request::Request, response::Response},
+};
+use std::fmt::Debug;
+
+/// Behaviour that is applied to each request sent to Elasticsearch, and to its response.
+///
+/// Middleware in the chain is called in the order in which it is registered for a request,
+/// and in the reverse order for the response. Each attempt to send an API call is a separate
+/// request, so middleware is called again when an API call is retried.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use elasticsearch::{
+/// #     Error,
+/// #     http::{
+/// #         headers::HeaderValue,
+/// #         middleware::Middleware,
+/// #         request::Request,
+/// #         transport::{TransportBuilder, SingleNodeConnectionPool},
+/// #     },
+/// # };
+/// #[derive(Debug)]
+/// struct RequestId;
+///
+/// impl Middleware for RequestId {
+///     fn on_request(&self, request: &mut Request) -> Result<(), Error> {
+///         request
+///             .headers_mut()
+///             .insert("x-request-id", HeaderValue::from_static("1234"));
+///         Ok(())
+///     }
+/// }
+///
+/// # fn doc() -> Result<(), Box<dyn std::error::Error>> {
+/// let conn_pool = SingleNodeConnectionPool::default();
+/// let transport = TransportBuilder::new(conn_pool)
+///     .middleware(RequestId)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait Middleware: Debug + Send + Sync {
+    /// Called before a request is sent. The request can be modified, or the API call failed
+    /// by returning an error.
+    fn on_request(&self, request: &mut Request) -> Result<(), Error> {
+        let _ = request;
+        Ok(())
+    }
+
+    /// Called when a response is received for a request
+    fn on_response(&self, request: &Request, response: &Response) {
+        let _ = (request, response);
+    }
+}
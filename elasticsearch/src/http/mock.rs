@@ -0,0 +1,322 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! Mock components for testing code that uses the client
+//!
+//! Requires the `test-util` feature. A [MockTransport] returns canned responses to the
+//! requests sent through it, and records each request so that tests can assert on them,
+//! without needing a running Elasticsearch cluster.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use elasticsearch::{
+//! #     Elasticsearch, SearchParts,
+//! #     http::{mock::{MockResponse, MockTransport}, Method, StatusCode},
+//! # };
+//! # use serde_json::json;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mock = MockTransport::new().on(
+//!     Method::Post,
+//!     "/*/_search",
+//!     MockResponse::new(StatusCode::OK).json(&json!({"hits": {"hits": []}})),
+//! );
+//! let client = Elasticsearch::new(mock.transport());
+//!
+//! let response = client
+//!     .search(SearchParts::Index(&["tweets"]))
+//!     .send()
+//!     .await?;
+//!
+//! assert_eq!(response.status_code(), StatusCode::OK);
+//! assert_eq!(mock.requests()[0].url().path(), "/tweets/_search");
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::http::{
+    client::{BoxFuture, HttpClient, HttpError, HttpResponse},
+    headers::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE, DEFAULT_CONTENT_TYPE},
+    request::Request,
+    transport::{SingleNodeConnectionPool, Transport, TransportBuilder},
+    Method, StatusCode,
+};
+use bytes::Bytes;
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use url::Url;
+
+/// A canned response returned by a [MockTransport]
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Bytes,
+    failure: Option<Failure>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Failure {
+    Connect,
+    Timeout,
+}
+
+impl MockResponse {
+    /// Creates a new instance of a response with the given status code and an empty body
+    pub fn new(status: StatusCode) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: Bytes::new(),
+            failure: None,
+        }
+    }
+
+    /// Creates a response that fails the request with a connection error, as when a
+    /// node cannot be reached
+    pub fn connection_error() -> Self {
+        Self {
+            failure: Some(Failure::Connect),
+            ..Self::new(StatusCode::OK)
+        }
+    }
+
+    /// Creates a response that fails the request with a timeout error
+    pub fn timeout() -> Self {
+        Self {
+            failure: Some(Failure::Timeout),
+            ..Self::new(StatusCode::OK)
+        }
+    }
+
+    /// Adds a header to the response
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(key, value);
+        self
+    }
+
+    /// Sets the body of the response
+    pub fn body<B>(mut self, body: B) -> Self
+    where
+        B: Into<Bytes>,
+    {
+        self.body = body.into();
+        self
+    }
+
+    /// Sets the body of the response to the value serialized to JSON, and the
+    /// `Content-Type` header to `application/json`
+    pub fn json<T>(mut self, body: &T) -> Self
+    where
+        T: Serialize + ?Sized,
+    {
+        self.body = serde_json::to_vec(body)
+            .expect("response body serializes to JSON")
+            .into();
+        self.headers
+            .insert(CONTENT_TYPE, HeaderValue::from_static(DEFAULT_CONTENT_TYPE));
+        self
+    }
+
+    fn into_result(self, url: Url) -> Result<HttpResponse, HttpError> {
+        match self.failure {
+            Some(Failure::Connect) => Err(HttpError::connect(format!(
+                "mock connection error for url ({})",
+                url
+            ))),
+            Some(Failure::Timeout) => Err(HttpError::timeout(format!(
+                "mock timeout for url ({})",
+                url
+            ))),
+            None => Ok(HttpResponse::from_bytes(
+                self.status,
+                self.headers,
+                url,
+                self.body,
+            )),
+        }
+    }
+}
+
+/// A mock that returns canned responses to requests, and records the requests it receives.
+///
+/// Responses are registered for a method and a path pattern, in which `*` matches any
+/// characters within a single path segment. Responses registered for the same method and
+/// pattern are returned in the order in which they were registered, with the last response
+/// returned for all subsequent requests. When more than one pattern matches a request, the
+/// pattern registered first is used. A request that matches no pattern fails with an error.
+///
+/// Clones of a [MockTransport] share the same responses and recorded requests.
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<MockState>>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    routes: Vec<Route>,
+    requests: Vec<Request>,
+}
+
+#[derive(Debug)]
+struct Route {
+    method: Method,
+    pattern: String,
+    responses: VecDeque<MockResponse>,
+}
+
+impl MockTransport {
+    /// Creates a new instance of [MockTransport] with no responses
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a response to return for requests with the given method, whose path matches
+    /// the pattern
+    pub fn on(self, method: Method, pattern: &str, response: MockResponse) -> Self {
+        {
+            let mut state = self.state.lock().unwrap();
+            match state
+                .routes
+                .iter_mut()
+                .find(|r| r.method == method && r.pattern == pattern)
+            {
+                Some(route) => route.responses.push_back(response),
+                None => state.routes.push(Route {
+                    method,
+                    pattern: pattern.into(),
+                    responses: vec![response].into(),
+                }),
+            }
+        }
+        self
+    }
+
+    /// Creates a [Transport] that sends requests to this mock, to pass to
+    /// [Elasticsearch::new](crate::Elasticsearch::new)
+    pub fn transport(&self) -> Transport {
+        self.transport_builder()
+            .build()
+            .expect("mock transport builds")
+    }
+
+    /// Creates a [TransportBuilder] that sends requests to this mock, for configuring
+    /// other options of the transport
+    pub fn transport_builder(&self) -> TransportBuilder {
+        TransportBuilder::new(SingleNodeConnectionPool::default()).http_client(self.clone())
+    }
+
+    /// The requests received, in the order in which they were received
+    pub fn requests(&self) -> Vec<Request> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    /// Clears the recorded requests
+    pub fn clear_requests(&self) {
+        self.state.lock().unwrap().requests.clear();
+    }
+}
+
+impl HttpClient for MockTransport {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<HttpResponse, HttpError>> {
+        let url = request.url().clone();
+        let response = {
+            let mut state = self.state.lock().unwrap();
+            let response = state
+                .routes
+                .iter_mut()
+                .find(|r| r.method == request.method() && matches(&r.pattern, url.path()))
+                .map(|r| {
+                    if r.responses.len() > 1 {
+                        r.responses.pop_front().unwrap()
+                    } else {
+                        r.responses[0].clone()
+                    }
+                });
+            let method = request.method();
+            state.requests.push(request);
+            match response {
+                Some(r) => r.into_result(url),
+                None => Err(HttpError::other(format!(
+                    "no mock response for {:?} {}",
+                    method,
+                    url.path()
+                ))),
+            }
+        };
+
+        Box::pin(async move { response })
+    }
+}
+
+/// Whether the path matches the pattern, in which `*` matches any characters within a
+/// single path segment
+fn matches(pattern: &str, path: &str) -> bool {
+    let pattern_segments: Vec<&str> = pattern.trim_start_matches('/').split('/').collect();
+    let path_segments: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+    pattern_segments.len() == path_segments.len()
+        && pattern_segments
+            .iter()
+            .zip(path_segments.iter())
+            .all(|(p, s)| matches_segment(p, s))
+}
+
+fn matches_segment(pattern: &str, segment: &str) -> bool {
+    match pattern.find('*') {
+        None => pattern == segment,
+        Some(i) => {
+            let (prefix, rest) = (&pattern[..i], &pattern[i + 1..]);
+            if !segment.starts_with(prefix) {
+                return false;
+            }
+            let segment = &segment[prefix.len()..];
+            (0..=segment.len())
+                .filter(|j| segment.is_char_boundary(*j))
+                .any(|j| matches_segment(rest, &segment[j..]))
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::matches;
+
+    #[test]
+    fn matches_exact_path() {
+        assert!(matches("/", "/"));
+        assert!(matches("/_search", "/_search"));
+        assert!(!matches("/_search", "/tweets/_search"));
+    }
+
+    #[test]
+    fn matches_wildcard_segment() {
+        assert!(matches("/*/_search", "/tweets/_search"));
+        assert!(matches("/*/_doc/*", "/tweets/_doc/1"));
+        assert!(!matches("/*/_search", "/_search"));
+        assert!(!matches("/*", "/tweets/_search"));
+    }
+
+    #[test]
+    fn matches_wildcard_within_segment() {
+        assert!(matches("/tweets-*/_search", "/tweets-2020/_search"));
+        assert!(matches("/*-2020/_search", "/tweets-2020/_search"));
+        assert!(!matches("/tweets-*/_search", "/posts-2020/_search"));
+    }
+}
@@ -18,9 +18,19 @@
  */
 //! HTTP components
 
+#[cfg(feature = "test-util")]
+pub mod cassette;
+pub mod client;
 pub mod headers;
+pub mod metrics;
+pub mod middleware;
+#[cfg(feature = "test-util")]
+pub mod mock;
 pub mod request;
 pub mod response;
+pub mod retry;
+pub mod selector;
+pub mod trace_context;
 pub mod transport;
 
 pub use reqwest::StatusCode;
@@ -18,11 +18,16 @@
  */
 //! HTTP request components
 
-use crate::error::Error;
+use crate::{
+    error::Error,
+    http::{headers::HeaderMap, Method},
+};
 use bytes::buf::BufMutExt;
 use bytes::{BufMut, Bytes, BytesMut};
 use percent_encoding::AsciiSet;
 use serde::Serialize;
+use std::time::Duration;
+use url::Url;
 
 // similar to percent-encoding's NON_ALPHANUMERIC AsciiSet, but with some characters removed
 pub(crate) const PARTS_ENCODED: &AsciiSet = &percent_encoding::NON_ALPHANUMERIC
@@ -185,6 +190,104 @@ impl Body for () {
     }
 }
 
+/// A HTTP request to send to an Elasticsearch node.
+///
+/// A request is inspected and modified by [Middleware](crate::http::middleware::Middleware),
+/// and sent by a [HttpClient](crate::http::client::HttpClient).
+#[derive(Debug, Clone)]
+pub struct Request {
+    method: Method,
+    url: Url,
+    headers: HeaderMap,
+    body: Option<Bytes>,
+    timeout: Option<Duration>,
+}
+
+impl Request {
+    /// Creates a new instance of a request, with no headers, body or timeout
+    pub fn new(method: Method, url: Url) -> Self {
+        Self {
+            method,
+            url,
+            headers: HeaderMap::new(),
+            body: None,
+            timeout: None,
+        }
+    }
+
+    /// The HTTP method of the request
+    pub fn method(&self) -> Method {
+        self.method
+    }
+
+    /// The URL of the request, including the query string
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// A mutable reference to the URL of the request
+    pub fn url_mut(&mut self) -> &mut Url {
+        &mut self.url
+    }
+
+    /// The headers of the request
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// A mutable reference to the headers of the request
+    pub fn headers_mut(&mut self) -> &mut HeaderMap {
+        &mut self.headers
+    }
+
+    /// The body of the request, as it is sent on the wire. When request body compression
+    /// is enabled, these are the compressed bytes.
+    pub fn body(&self) -> Option<&Bytes> {
+        self.body.as_ref()
+    }
+
+    /// Sets the body of the request
+    pub fn set_body(&mut self, body: Option<Bytes>) {
+        self.body = body;
+    }
+
+    /// The timeout for the request, applied from when the request starts connecting
+    /// until the response body has finished
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Sets the timeout for the request
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.timeout = timeout;
+    }
+}
+
+/// Options for an API call sent with
+/// [Transport::send_with_options](crate::http::transport::Transport::send_with_options)
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    timeout: Option<Duration>,
+}
+
+impl RequestOptions {
+    /// Creates a new instance of [RequestOptions]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a timeout for the API call, overriding the timeout set with
+    /// [TransportBuilder::timeout](crate::http::transport::TransportBuilder::timeout)
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub(crate) fn request_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::http::request::{Body, JsonBody, NdBody};
@@ -19,15 +19,22 @@
 //! HTTP response components
 
 use crate::error::Error;
-use crate::http::{headers::HeaderMap, Method, StatusCode, Url};
+use crate::http::{
+    client::HttpResponse,
+    headers::{HeaderMap, CONTENT_LENGTH, CONTENT_TYPE},
+    Method, StatusCode, Url,
+};
+use encoding_rs::{Encoding, UTF_8};
+use mime::Mime;
 use serde::de::DeserializeOwned;
 
 /// A response from Elasticsearch
-pub struct Response(reqwest::Response, Method);
+#[derive(Debug)]
+pub struct Response(HttpResponse, Method);
 
 impl Response {
     /// Creates a new instance of an Elasticsearch response
-    pub fn new(response: reqwest::Response, method: Method) -> Self {
+    pub fn new(response: HttpResponse, method: Method) -> Self {
         Self(response, method)
     }
 
@@ -39,31 +46,44 @@ impl Response {
     /// - The response is compressed and automatically decoded (thus changing
     ///   the actual decoded length).
     pub fn content_length(&self) -> Option<u64> {
-        self.0.content_length()
+        self.0
+            .headers()
+            .get(CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok())
     }
 
     /// Gets the response content-type.
     pub fn content_type(&self) -> &str {
         self.0
             .headers()
-            .get(crate::http::headers::CONTENT_TYPE)
+            .get(CONTENT_TYPE)
             .and_then(|value| value.to_str().ok())
             .unwrap()
     }
 
     /// Turn the response into an [Error] if Elasticsearch returned an error.
     pub fn error_for_status_code(self) -> Result<Self, Error> {
-        match self.0.error_for_status_ref() {
-            Ok(_) => Ok(self),
-            Err(err) => Err(err.into()),
+        match self.status_error() {
+            None => Ok(self),
+            Some(err) => Err(err),
         }
     }
 
     /// Turn the response into an [Error] if Elasticsearch returned an error.
     pub fn error_for_status_code_ref(&self) -> Result<&Self, Error> {
-        match self.0.error_for_status_ref() {
-            Ok(_) => Ok(self),
-            Err(err) => Err(err.into()),
+        match self.status_error() {
+            None => Ok(self),
+            Some(err) => Err(err),
+        }
+    }
+
+    fn status_error(&self) -> Option<Error> {
+        let status = self.0.status();
+        if status.is_client_error() || status.is_server_error() {
+            Some(Error::status(status, self.0.url().clone()))
+        } else {
+            None
         }
     }
 
@@ -74,7 +94,8 @@ impl Response {
     where
         B: DeserializeOwned,
     {
-        let body = self.0.json::<B>().await?;
+        let bytes = self.0.bytes().await?;
+        let body = serde_json::from_slice(&bytes)?;
         Ok(body)
     }
 
@@ -95,10 +116,25 @@ impl Response {
 
     /// Asynchronously reads the response body as plain text
     ///
+    /// The response body is decoded with the charset of the Content-Type header,
+    /// defaulting to UTF-8 when the header has no charset or an unknown charset.
+    /// Malformed sequences are replaced with the replacement character.
+    ///
     /// Reading the response body consumes `self`
     pub async fn text(self) -> Result<String, Error> {
-        let body = self.0.text().await?;
-        Ok(body)
+        let encoding = self
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<Mime>().ok())
+            .and_then(|mime| {
+                mime.get_param(mime::CHARSET)
+                    .and_then(|charset| Encoding::for_label(charset.as_str().as_bytes()))
+            })
+            .unwrap_or(UTF_8);
+        let bytes = self.0.bytes().await?;
+        let (text, _, _) = encoding.decode(&bytes);
+        Ok(text.into_owned())
     }
 
     /// Gets the request URL
@@ -0,0 +1,204 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! Retry components
+
+use crate::{error::Error, http::response::Response, http::StatusCode};
+use rand::Rng;
+use std::time::Duration;
+
+/// The default maximum number of times an API call is retried
+pub static DEFAULT_MAX_RETRIES: usize = 3;
+
+/// The default initial period of time to wait before retrying an API call
+pub static DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(100);
+
+/// The default maximum period of time to wait before retrying an API call
+pub static DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(10);
+
+/// A policy that determines whether, and after how long, an API call that failed is retried.
+///
+/// Each retry is sent to the next [Connection](crate::http::transport::Connection) from the
+/// connection pool. Between attempts, the transport waits for a random period of time between
+/// zero and an exponentially increasing backoff, starting at [RetryPolicy::initial_backoff] and
+/// capped at [RetryPolicy::max_backoff].
+///
+/// By default, an API call is retried up to [DEFAULT_MAX_RETRIES] times when the connection
+/// to a node fails, or when the response has a status code of 429, 502, 503 or 504.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use elasticsearch::{
+/// #     http::{retry::RetryPolicy, transport::{TransportBuilder, SingleNodeConnectionPool}, StatusCode},
+/// # };
+/// # use std::time::Duration;
+/// # fn doc() -> Result<(), Box<dyn std::error::Error>> {
+/// let conn_pool = SingleNodeConnectionPool::default();
+/// let retry_policy = RetryPolicy::default()
+///     .max_retries(5)
+///     .retry_on_status(&[StatusCode::SERVICE_UNAVAILABLE])
+///     .max_backoff(Duration::from_secs(30));
+/// let transport = TransportBuilder::new(conn_pool)
+///     .retry_policy(retry_policy)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_retries: usize,
+    retry_on_status: Vec<StatusCode>,
+    retry_on_connection_error: bool,
+    retry_on_timeout: bool,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: DEFAULT_MAX_RETRIES,
+            retry_on_status: vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            retry_on_connection_error: true,
+            retry_on_timeout: false,
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// The maximum number of times to retry an API call. Defaults to [DEFAULT_MAX_RETRIES].
+    pub fn max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// The response status codes for which to retry an API call.
+    /// Defaults to 429, 502, 503 and 504.
+    pub fn retry_on_status(mut self, status_codes: &[StatusCode]) -> Self {
+        self.retry_on_status = status_codes.to_vec();
+        self
+    }
+
+    /// Whether to retry an API call when the connection to a node fails. Defaults to `true`.
+    pub fn retry_on_connection_error(mut self, retry: bool) -> Self {
+        self.retry_on_connection_error = retry;
+        self
+    }
+
+    /// Whether to retry an API call when it times out. Defaults to `false`, because
+    /// the node may have processed the API call before the timeout elapsed.
+    pub fn retry_on_timeout(mut self, retry: bool) -> Self {
+        self.retry_on_timeout = retry;
+        self
+    }
+
+    /// The initial period of time to wait before retrying an API call.
+    /// Defaults to [DEFAULT_INITIAL_BACKOFF].
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// The maximum period of time to wait before retrying an API call.
+    /// Defaults to [DEFAULT_MAX_BACKOFF].
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Whether to retry an API call, given the number of retries made so far
+    /// and the result of the last attempt
+    pub(crate) fn should_retry(&self, retries: usize, result: &Result<Response, Error>) -> bool {
+        if retries >= self.max_retries {
+            return false;
+        }
+
+        match result {
+            Ok(response) => self.retry_on_status.contains(&response.status_code()),
+            Err(err) => {
+                (self.retry_on_connection_error && err.is_connection())
+                    || (self.retry_on_timeout && err.is_timeout())
+            }
+        }
+    }
+
+    /// The period of time to wait before making the given retry, which is a random
+    /// duration between zero and the exponential backoff for the retry
+    pub(crate) fn backoff(&self, retries: usize) -> Duration {
+        let max = self.backoff_ceiling(retries);
+        if max == Duration::from_millis(0) {
+            return max;
+        }
+
+        let nanos = rand::thread_rng().gen_range(0, max.as_nanos() as u64);
+        Duration::from_nanos(nanos)
+    }
+
+    /// The exponential backoff for the given retry, capped at the maximum backoff
+    fn backoff_ceiling(&self, retries: usize) -> Duration {
+        // 2^retries, limiting the exponent to avoid overflow
+        let factor = 1u32 << retries.min(31);
+        self.initial_backoff
+            .checked_mul(factor)
+            .map_or(self.max_backoff, |b| b.min(self.max_backoff))
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::http::retry::RetryPolicy;
+    use std::time::Duration;
+
+    #[test]
+    fn backoff_grows_exponentially() {
+        let policy = RetryPolicy::default()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_secs(1));
+
+        assert_eq!(policy.backoff_ceiling(0), Duration::from_millis(100));
+        assert_eq!(policy.backoff_ceiling(1), Duration::from_millis(200));
+        assert_eq!(policy.backoff_ceiling(2), Duration::from_millis(400));
+        assert_eq!(policy.backoff_ceiling(3), Duration::from_millis(800));
+        assert_eq!(policy.backoff_ceiling(4), Duration::from_secs(1));
+        assert_eq!(policy.backoff_ceiling(100), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn backoff_is_jittered_within_ceiling() {
+        let policy = RetryPolicy::default();
+        for retries in 0..10 {
+            assert!(policy.backoff(retries) < policy.backoff_ceiling(retries));
+        }
+    }
+
+    #[test]
+    fn zero_backoff() {
+        let policy = RetryPolicy::default()
+            .initial_backoff(Duration::from_millis(0))
+            .max_backoff(Duration::from_millis(0));
+        assert_eq!(policy.backoff(3), Duration::from_millis(0));
+    }
+}
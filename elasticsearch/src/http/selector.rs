@@ -0,0 +1,283 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! Connection selection components
+//!
+//! A [ConnectionSelector] chooses the [Connection] to which an API call is sent from the
+//! live connections of a connection pool that manages more than one connection, such as
+//! [StaticNodeListConnectionPool](crate::http::transport::StaticNodeListConnectionPool)
+//! and [SniffingConnectionPool](crate::http::transport::SniffingConnectionPool).
+//!
+//! # Example
+//!
+//! Prefer the nodes in the client's own availability zone, selecting between them in
+//! round-robin order
+//!
+//! ```rust,no_run
+//! # use elasticsearch::http::{
+//! #     selector::{RoundRobinSelector, ZoneAwareSelector},
+//! #     transport::{SniffingConnectionPool, TransportBuilder},
+//! # };
+//! # use url::Url;
+//! # fn doc() -> Result<(), Box<dyn std::error::Error>> {
+//! let url = Url::parse("http://localhost:9200")?;
+//! let selector = ZoneAwareSelector::new("zone", "us-east-1a", RoundRobinSelector::default());
+//! let conn_pool = SniffingConnectionPool::new(vec![url])?.selector(selector);
+//! let transport = TransportBuilder::new(conn_pool).build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::http::transport::Connection;
+use rand::Rng;
+use std::{
+    fmt,
+    fmt::Debug,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+/// Selects the [Connection] to which an API call is sent
+pub trait ConnectionSelector: Debug + dyn_clone::DynClone + Send + Sync {
+    /// Selects a connection from the live connections of a pool, of which there is
+    /// always at least one.
+    fn select<'a>(&self, connections: &[&'a Connection]) -> &'a Connection;
+}
+
+clone_trait_object!(ConnectionSelector);
+
+/// Selects connections in round-robin order.
+///
+/// Clones of the selector share the position in the rotation.
+#[derive(Debug, Clone, Default)]
+pub struct RoundRobinSelector {
+    index: Arc<AtomicUsize>,
+}
+
+impl ConnectionSelector for RoundRobinSelector {
+    fn select<'a>(&self, connections: &[&'a Connection]) -> &'a Connection {
+        let idx = self.index.fetch_add(1, Ordering::Relaxed);
+        connections[idx % connections.len()]
+    }
+}
+
+/// Selects connections at random
+#[derive(Debug, Clone, Default)]
+pub struct RandomSelector;
+
+impl ConnectionSelector for RandomSelector {
+    fn select<'a>(&self, connections: &[&'a Connection]) -> &'a Connection {
+        let idx = rand::thread_rng().gen_range(0, connections.len());
+        connections[idx]
+    }
+}
+
+/// Selects connections at random, in proportion to a weight given to each connection.
+///
+/// A connection with a weight of zero is selected only when all connections have
+/// a weight of zero, in which case a connection is selected uniformly at random.
+#[derive(Clone)]
+pub struct WeightedSelector {
+    weight: Arc<dyn Fn(&Connection) -> u32 + Send + Sync>,
+}
+
+impl WeightedSelector {
+    /// Creates a new instance of [WeightedSelector], with a function that returns the
+    /// weight of a connection. For example, a weight can be derived from a node attribute
+    /// that indicates the capacity of the node.
+    pub fn new<F>(weight: F) -> Self
+    where
+        F: Fn(&Connection) -> u32 + Send + Sync + 'static,
+    {
+        Self {
+            weight: Arc::new(weight),
+        }
+    }
+}
+
+impl Debug for WeightedSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeightedSelector").finish()
+    }
+}
+
+impl ConnectionSelector for WeightedSelector {
+    fn select<'a>(&self, connections: &[&'a Connection]) -> &'a Connection {
+        let weights: Vec<u64> = connections
+            .iter()
+            .map(|c| u64::from((self.weight)(c)))
+            .collect();
+        let total: u64 = weights.iter().sum();
+        if total == 0 {
+            return RandomSelector.select(connections);
+        }
+
+        let mut point = rand::thread_rng().gen_range(0, total);
+        for (connection, weight) in connections.iter().zip(weights) {
+            if point < weight {
+                return connection;
+            }
+            point -= weight;
+        }
+
+        unreachable!("point is less than the total weight")
+    }
+}
+
+/// Prefers connections to nodes in a given zone, identified by the value of a node attribute
+/// such as `zone` or `availability_zone`, and delegates to another [ConnectionSelector] to
+/// select between them. When there are no live connections to nodes in the zone, the other
+/// selector selects between all live connections.
+///
+/// Node attributes are known only for connections discovered by sniffing the cluster.
+#[derive(Debug, Clone)]
+pub struct ZoneAwareSelector {
+    attribute: String,
+    zone: String,
+    selector: Box<dyn ConnectionSelector>,
+}
+
+impl ZoneAwareSelector {
+    /// Creates a new instance of [ZoneAwareSelector], that prefers connections to nodes with
+    /// the `attribute` node attribute set to `zone`, and selects between connections using
+    /// `selector`.
+    pub fn new<A, Z, S>(attribute: A, zone: Z, selector: S) -> Self
+    where
+        A: Into<String>,
+        Z: Into<String>,
+        S: ConnectionSelector + 'static,
+    {
+        Self {
+            attribute: attribute.into(),
+            zone: zone.into(),
+            selector: Box::new(selector),
+        }
+    }
+}
+
+impl ConnectionSelector for ZoneAwareSelector {
+    fn select<'a>(&self, connections: &[&'a Connection]) -> &'a Connection {
+        let local: Vec<&'a Connection> = connections
+            .iter()
+            .copied()
+            .filter(|c| c.attribute(&self.attribute) == Some(self.zone.as_str()))
+            .collect();
+
+        if local.is_empty() {
+            self.selector.select(connections)
+        } else {
+            self.selector.select(&local)
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::http::{
+        selector::{
+            ConnectionSelector, RandomSelector, RoundRobinSelector, WeightedSelector,
+            ZoneAwareSelector,
+        },
+        transport::Connection,
+    };
+    use std::collections::BTreeMap;
+    use url::Url;
+
+    fn connection(url: &str, zone: &str) -> Connection {
+        let mut attributes = BTreeMap::new();
+        attributes.insert("zone".to_string(), zone.to_string());
+        Connection::new(Url::parse(url).unwrap()).with_attributes(attributes)
+    }
+
+    fn connections() -> Vec<Connection> {
+        vec![
+            connection("http://10.1.2.1:9200", "a"),
+            connection("http://10.1.2.2:9200", "b"),
+            connection("http://10.1.2.3:9200", "b"),
+        ]
+    }
+
+    fn select_urls<S: ConnectionSelector>(selector: &S, n: usize) -> Vec<String> {
+        let connections = connections();
+        let refs: Vec<&Connection> = connections.iter().collect();
+        (0..n)
+            .map(|_| selector.select(&refs).url().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn round_robin_selector() {
+        let urls = select_urls(&RoundRobinSelector::default(), 4);
+        assert_eq!(
+            urls,
+            vec![
+                "http://10.1.2.1:9200/",
+                "http://10.1.2.2:9200/",
+                "http://10.1.2.3:9200/",
+                "http://10.1.2.1:9200/"
+            ]
+        );
+    }
+
+    #[test]
+    fn random_selector() {
+        let urls = select_urls(&RandomSelector, 20);
+        assert!(urls.iter().all(|u| u.starts_with("http://10.1.2.")));
+    }
+
+    #[test]
+    fn weighted_selector_skips_zero_weights() {
+        let selector = WeightedSelector::new(|c| match c.url().host_str() {
+            Some("10.1.2.2") => 1,
+            _ => 0,
+        });
+        let urls = select_urls(&selector, 20);
+        assert!(urls.iter().all(|u| u == "http://10.1.2.2:9200/"));
+    }
+
+    #[test]
+    fn zone_aware_selector_prefers_zone() {
+        let selector = ZoneAwareSelector::new("zone", "b", RoundRobinSelector::default());
+        let urls = select_urls(&selector, 4);
+        assert_eq!(
+            urls,
+            vec![
+                "http://10.1.2.2:9200/",
+                "http://10.1.2.3:9200/",
+                "http://10.1.2.2:9200/",
+                "http://10.1.2.3:9200/"
+            ]
+        );
+    }
+
+    #[test]
+    fn zone_aware_selector_falls_back_to_all_connections() {
+        let selector = ZoneAwareSelector::new("zone", "c", RoundRobinSelector::default());
+        let urls = select_urls(&selector, 3);
+        assert_eq!(
+            urls,
+            vec![
+                "http://10.1.2.1:9200/",
+                "http://10.1.2.2:9200/",
+                "http://10.1.2.3:9200/"
+            ]
+        );
+    }
+}
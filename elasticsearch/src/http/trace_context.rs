@@ -0,0 +1,345 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! W3C trace context propagation components
+//!
+//! [TraceContextMiddleware] injects the current [W3C trace context](https://www.w3.org/TR/trace-context/)
+//! into each request sent to Elasticsearch, using the `traceparent` and `tracestate` headers.
+//! The current trace context is retrieved from a [TraceContextProvider], which integrates with
+//! the distributed tracing stack used by an application. Optionally, the `X-Opaque-Id` header
+//! can be derived from the trace id, so that slow logs and task listings in Elasticsearch can be
+//! correlated with distributed traces.
+//!
+//! With the `opentelemetry` feature enabled, [TraceContextMiddleware::default] propagates the
+//! current [OpenTelemetry](https://docs.rs/opentelemetry) trace context with [OpenTelemetryContext].
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use elasticsearch::http::{
+//! #     trace_context::{TraceContext, TraceContextMiddleware},
+//! #     transport::{SingleNodeConnectionPool, TransportBuilder},
+//! # };
+//! # fn current_traceparent() -> Option<String> { None }
+//! # fn doc() -> Result<(), Box<dyn std::error::Error>> {
+//! let trace_context = TraceContextMiddleware::new(|| {
+//!     current_traceparent().and_then(|t| TraceContext::parse(&t).ok())
+//! })
+//! .opaque_id_from_trace_id(true);
+//!
+//! let transport = TransportBuilder::new(SingleNodeConnectionPool::default())
+//!     .middleware(trace_context)
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    error::Error,
+    http::{
+        headers::{HeaderName, HeaderValue, TRACEPARENT, TRACESTATE, X_OPAQUE_ID},
+        middleware::Middleware,
+        request::Request,
+    },
+};
+use std::{fmt, fmt::Debug, sync::Arc};
+
+/// The version of the traceparent header format that is supported
+static TRACEPARENT_VERSION: &str = "00";
+
+/// A [W3C trace context](https://www.w3.org/TR/trace-context/), identifying the trace and
+/// span within which a call to Elasticsearch is made.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceContext {
+    trace_id: String,
+    parent_id: String,
+    flags: u8,
+    trace_state: Option<String>,
+}
+
+impl TraceContext {
+    /// Creates a new instance of a trace context.
+    ///
+    /// * `trace_id`: the id of the trace, as 32 hex characters, not all zero
+    /// * `parent_id`: the id of the current span, as 16 hex characters, not all zero
+    /// * `sampled`: whether the trace is sampled
+    pub fn new(trace_id: &str, parent_id: &str, sampled: bool) -> Result<Self, Error> {
+        let trace_id = parse_id(trace_id, 32, "trace id")?;
+        let parent_id = parse_id(parent_id, 16, "parent id")?;
+        Ok(Self {
+            trace_id,
+            parent_id,
+            flags: if sampled { 1 } else { 0 },
+            trace_state: None,
+        })
+    }
+
+    /// Parses a trace context from the value of a `traceparent` header
+    pub fn parse(traceparent: &str) -> Result<Self, Error> {
+        let parts: Vec<&str> = traceparent.trim().split('-').collect();
+        match parts.as_slice() {
+            [version, trace_id, parent_id, flags]
+                if *version == TRACEPARENT_VERSION && flags.len() == 2 =>
+            {
+                let flags = u8::from_str_radix(flags, 16)
+                    .map_err(|_| Error::lib(format!("invalid traceparent: {}", traceparent)))?;
+                let mut context = Self::new(trace_id, parent_id, false)?;
+                context.flags = flags;
+                Ok(context)
+            }
+            _ => Err(Error::lib(format!("invalid traceparent: {}", traceparent))),
+        }
+    }
+
+    /// Sets the vendor specific trace state, sent in the `tracestate` header
+    pub fn trace_state(mut self, trace_state: impl Into<String>) -> Self {
+        self.trace_state = Some(trace_state.into());
+        self
+    }
+
+    /// The id of the trace
+    pub fn trace_id(&self) -> &str {
+        &self.trace_id
+    }
+
+    /// The id of the current span
+    pub fn parent_id(&self) -> &str {
+        &self.parent_id
+    }
+
+    /// Whether the trace is sampled
+    pub fn sampled(&self) -> bool {
+        self.flags & 1 == 1
+    }
+
+    /// The value of the `traceparent` header for the trace context
+    pub fn traceparent(&self) -> String {
+        format!(
+            "{}-{}-{}-{:02x}",
+            TRACEPARENT_VERSION, self.trace_id, self.parent_id, self.flags
+        )
+    }
+
+    /// The value of the `tracestate` header for the trace context, if any
+    pub fn tracestate(&self) -> Option<&str> {
+        self.trace_state.as_deref()
+    }
+}
+
+/// Validates an id of hex characters of the given length, returning it in lower case
+fn parse_id(id: &str, len: usize, name: &str) -> Result<String, Error> {
+    if id.len() != len || !id.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(Error::lib(format!(
+            "{} must be {} hex characters: {}",
+            name, len, id
+        )));
+    }
+    if id.chars().all(|c| c == '0') {
+        return Err(Error::lib(format!("{} must not be all zero", name)));
+    }
+    Ok(id.to_ascii_lowercase())
+}
+
+/// Provides the trace context that is current when a request is sent
+pub trait TraceContextProvider: Send + Sync {
+    /// The current trace context, if a call is made within a trace
+    fn current(&self) -> Option<TraceContext>;
+}
+
+impl<F> TraceContextProvider for F
+where
+    F: Fn() -> Option<TraceContext> + Send + Sync,
+{
+    fn current(&self) -> Option<TraceContext> {
+        self()
+    }
+}
+
+/// A [TraceContextProvider] that propagates the trace context of the current
+/// [OpenTelemetry](https://docs.rs/opentelemetry) span.
+///
+/// The trace id, sampled flag and trace state are those of the current span. Each request
+/// to Elasticsearch is given a random parent id of its own, so that it can be told apart from
+/// other requests made within the same span, such as retries.
+#[cfg(feature = "opentelemetry")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OpenTelemetryContext;
+
+#[cfg(feature = "opentelemetry")]
+impl TraceContextProvider for OpenTelemetryContext {
+    fn current(&self) -> Option<TraceContext> {
+        use opentelemetry_crate::trace::TraceContextExt;
+
+        let context = opentelemetry_crate::Context::current();
+        let span = context.span();
+        let span_context = span.span_context();
+        if !span_context.is_valid() {
+            return None;
+        }
+        let trace_state = span_context.trace_state().header();
+        Some(TraceContext {
+            trace_id: span_context.trace_id().to_string(),
+            parent_id: random_parent_id(),
+            flags: span_context.trace_flags().to_u8(),
+            trace_state: if trace_state.is_empty() {
+                None
+            } else {
+                Some(trace_state)
+            },
+        })
+    }
+}
+
+/// A random, non zero parent id
+#[cfg(feature = "opentelemetry")]
+fn random_parent_id() -> String {
+    format!("{:016x}", rand::random::<u64>().max(1))
+}
+
+/// A [Middleware] that injects the current trace context into each request.
+///
+/// The `traceparent` and `tracestate` headers are not overwritten when they have
+/// been set for an API call.
+#[derive(Clone)]
+pub struct TraceContextMiddleware {
+    provider: Arc<dyn TraceContextProvider>,
+    opaque_id_from_trace_id: bool,
+}
+
+impl TraceContextMiddleware {
+    /// Creates a new instance of [TraceContextMiddleware] that retrieves the current trace
+    /// context from the provider
+    pub fn new<P>(provider: P) -> Self
+    where
+        P: TraceContextProvider + 'static,
+    {
+        Self {
+            provider: Arc::new(provider),
+            opaque_id_from_trace_id: false,
+        }
+    }
+
+    /// Whether to set the `X-Opaque-Id` header to the trace id, when it has not been set
+    /// for an API call or with [TransportBuilder::header](crate::http::transport::TransportBuilder::header).
+    ///
+    /// By default, the `X-Opaque-Id` header is not set.
+    pub fn opaque_id_from_trace_id(mut self, enabled: bool) -> Self {
+        self.opaque_id_from_trace_id = enabled;
+        self
+    }
+}
+
+#[cfg(feature = "opentelemetry")]
+impl Default for TraceContextMiddleware {
+    /// Creates a new instance of [TraceContextMiddleware] that propagates the trace context
+    /// of the current OpenTelemetry span with [OpenTelemetryContext]
+    fn default() -> Self {
+        Self::new(OpenTelemetryContext)
+    }
+}
+
+impl Debug for TraceContextMiddleware {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TraceContextMiddleware")
+            .field("opaque_id_from_trace_id", &self.opaque_id_from_trace_id)
+            .finish()
+    }
+}
+
+impl Middleware for TraceContextMiddleware {
+    fn on_request(&self, request: &mut Request) -> Result<(), Error> {
+        let context = match self.provider.current() {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+
+        let headers = request.headers_mut();
+        if !headers.contains_key(TRACEPARENT) {
+            headers.insert(
+                HeaderName::from_static(TRACEPARENT),
+                HeaderValue::from_str(&context.traceparent()).unwrap(),
+            );
+            if let Some(state) = context.tracestate() {
+                if let Ok(value) = HeaderValue::from_str(state) {
+                    headers.insert(HeaderName::from_static(TRACESTATE), value);
+                }
+            }
+        }
+
+        if self.opaque_id_from_trace_id && !headers.contains_key(X_OPAQUE_ID) {
+            headers.insert(
+                HeaderName::from_static(X_OPAQUE_ID),
+                HeaderValue::from_str(context.trace_id()).unwrap(),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::TraceContext;
+
+    #[test]
+    fn parse_traceparent() {
+        let context =
+            TraceContext::parse("00-0AF7651916CD43DD8448EB211C80319C-b7ad6b7169203331-01").unwrap();
+        assert_eq!(context.trace_id(), "0af7651916cd43dd8448eb211c80319c");
+        assert_eq!(context.parent_id(), "b7ad6b7169203331");
+        assert!(context.sampled());
+        assert_eq!(
+            context.traceparent(),
+            "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01"
+        );
+    }
+
+    #[test]
+    fn parse_invalid_traceparent() {
+        assert!(TraceContext::parse("").is_err());
+        assert!(
+            TraceContext::parse("01-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01").is_err()
+        );
+        assert!(
+            TraceContext::parse("00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331").is_err()
+        );
+        assert!(
+            TraceContext::parse("00-00000000000000000000000000000000-b7ad6b7169203331-01").is_err()
+        );
+        assert!(
+            TraceContext::parse("00-0af7651916cd43dd8448eb211c80319c-000000000000000x-01").is_err()
+        );
+    }
+
+    #[test]
+    fn new_trace_context() {
+        let context = TraceContext::new(
+            "4bf92f3577b34da6a3ce929d0e0e4736",
+            "00f067aa0ba902b7",
+            false,
+        )
+        .unwrap()
+        .trace_state("congo=t61rcWkgMzE");
+        assert!(!context.sampled());
+        assert_eq!(
+            context.traceparent(),
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-00"
+        );
+        assert_eq!(context.tracestate(), Some("congo=t61rcWkgMzE"));
+    }
+}
@@ -19,13 +19,24 @@
 pub mod common;
 use common::*;
 
-use elasticsearch::auth::Credentials;
+use elasticsearch::{
+    auth::{Credentials, CredentialsProvider, FileCredentialsProvider, TokenCredentialsProvider},
+    http::StatusCode,
+    Elasticsearch,
+};
 
 use base64;
 use base64::write::EncoderWriter as Base64Encoder;
 // use std::fs::File;
 // use std::io::Read;
+use hyper::Method;
+use serde_json::{json, Value};
 use std::io::Write;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Mutex,
+};
+use std::time::Duration;
 
 #[tokio::test]
 async fn basic_auth_header() -> Result<(), failure::Error> {
@@ -52,6 +63,32 @@ async fn basic_auth_header() -> Result<(), failure::Error> {
     Ok(())
 }
 
+#[tokio::test]
+async fn basic_auth_header_from_url() -> Result<(), failure::Error> {
+    let server = server::http(move |req| async move {
+        let mut header_value = b"Basic ".to_vec();
+        {
+            let mut encoder = Base64Encoder::new(&mut header_value, base64::STANDARD);
+            write!(encoder, "user name:p@ss").unwrap();
+        }
+
+        assert_eq!(
+            req.headers()["authorization"],
+            String::from_utf8(header_value).unwrap()
+        );
+        assert_eq!(req.uri().path(), "/prefix/");
+        http::Response::default()
+    });
+
+    let client = Elasticsearch::from_url(&format!(
+        "http://user%20name:p%40ss@{}/prefix",
+        server.addr()
+    ))?;
+    let _response = client.ping().send().await?;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn api_key_header() -> Result<(), failure::Error> {
     let server = server::http(move |req| async move {
@@ -93,6 +130,417 @@ async fn bearer_header() -> Result<(), failure::Error> {
     Ok(())
 }
 
+/// A server that accepts only the `Bearer rotated` authorization header,
+/// counting the requests it receives
+fn rotated_token_server(requests: Arc<AtomicUsize>) -> server::Server {
+    server::http(move |req| {
+        let requests = requests.clone();
+        async move {
+            requests.fetch_add(1, Ordering::SeqCst);
+            let status = if req.headers()["authorization"] == "Bearer rotated" {
+                200
+            } else {
+                401
+            };
+            http::Response::builder()
+                .status(status)
+                .body(hyper::Body::empty())
+                .unwrap()
+        }
+    })
+}
+
+#[tokio::test]
+async fn credentials_provider_header() -> Result<(), failure::Error> {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let server = rotated_token_server(requests.clone());
+
+    let builder = client::create_builder(format!("http://{}", server.addr()).as_ref())
+        .auth(Credentials::Bearer("expired".into()))
+        .credentials_provider(|| async { Ok(Credentials::Bearer("rotated".into())) });
+
+    let client = client::create(builder);
+    let response = client.ping().send().await?;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn credentials_provider_refreshed_on_unauthorized() -> Result<(), failure::Error> {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let server = rotated_token_server(requests.clone());
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let builder = client::create_builder(format!("http://{}", server.addr()).as_ref())
+        .credentials_provider(move || {
+            let token = match calls.fetch_add(1, Ordering::SeqCst) {
+                0 => "expired",
+                _ => "rotated",
+            };
+            async move { Ok(Credentials::Bearer(token.into())) }
+        });
+
+    let client = client::create(builder);
+    let response = client.ping().send().await?;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn credentials_provider_retried_only_once_on_unauthorized() -> Result<(), failure::Error> {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let server = rotated_token_server(requests.clone());
+
+    let builder = client::create_builder(format!("http://{}", server.addr()).as_ref())
+        .credentials_provider(|| async { Ok(Credentials::Bearer("expired".into())) });
+
+    let client = client::create(builder);
+    let response = client.ping().send().await?;
+
+    assert_eq!(response.status_code(), StatusCode::UNAUTHORIZED);
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn static_credentials_not_retried_on_unauthorized() -> Result<(), failure::Error> {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let server = rotated_token_server(requests.clone());
+
+    let builder = client::create_builder(format!("http://{}", server.addr()).as_ref())
+        .auth(Credentials::Bearer("expired".into()));
+
+    let client = client::create(builder);
+    let response = client.ping().send().await?;
+
+    assert_eq!(response.status_code(), StatusCode::UNAUTHORIZED);
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+    Ok(())
+}
+
+/// A server that issues access tokens with the get token API that expire after
+/// `expires_in` seconds, if given, and accepts only the most recently issued access token,
+/// logging the token API calls it receives
+fn token_server(expires_in: Option<u64>, log: Arc<Mutex<Vec<String>>>) -> server::Server {
+    let issued = Arc::new(AtomicUsize::new(0));
+    server::http(move |req| {
+        let issued = issued.clone();
+        let log = log.clone();
+        async move {
+            let authorization = req
+                .headers()
+                .get("authorization")
+                .map(|v| v.to_str().unwrap().to_string())
+                .unwrap_or_default();
+            let method = req.method().clone();
+            let path = req.uri().path().to_string();
+            let bytes = hyper::body::to_bytes(req.into_body()).await.unwrap();
+
+            if path == "/_security/oauth2/token" {
+                let body: Value = serde_json::from_slice(&bytes).unwrap();
+                if method == Method::POST {
+                    let n = issued.fetch_add(1, Ordering::SeqCst);
+                    log.lock().unwrap().push(format!(
+                        "get {} {} {}",
+                        body["grant_type"].as_str().unwrap(),
+                        body["refresh_token"].as_str().unwrap_or("-"),
+                        authorization
+                    ));
+                    let mut response = json!({
+                        "access_token": format!("token-{}", n),
+                        "type": "Bearer",
+                        "refresh_token": format!("refresh-{}", n)
+                    });
+                    if let Some(expires_in) = expires_in {
+                        response["expires_in"] = json!(expires_in);
+                    }
+                    return http::Response::new(response.to_string().into());
+                } else {
+                    log.lock().unwrap().push(format!("invalidate {}", body));
+                    return http::Response::new(json!({}).to_string().into());
+                }
+            }
+
+            let current = format!("Bearer token-{}", issued.load(Ordering::SeqCst) - 1);
+            let status = if authorization == current { 200 } else { 401 };
+            http::Response::builder()
+                .status(status)
+                .body(hyper::Body::empty())
+                .unwrap()
+        }
+    })
+}
+
+fn basic(username: &str, password: &str) -> String {
+    let mut header_value = b"Basic ".to_vec();
+    {
+        let mut encoder = Base64Encoder::new(&mut header_value, base64::STANDARD);
+        write!(encoder, "{}:{}", username, password).unwrap();
+    }
+    String::from_utf8(header_value).unwrap()
+}
+
+#[tokio::test]
+async fn token_credentials_provider() -> Result<(), failure::Error> {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let server = token_server(Some(1200), log.clone());
+    let url = format!("http://{}", server.addr());
+
+    let tokens =
+        TokenCredentialsProvider::password(client::create_for_url(&url), "elastic", "changeme");
+    let builder = client::create_builder(&url).credentials_provider(tokens.clone());
+    let client = client::create(builder);
+
+    for _ in 0..2 {
+        let response = client.ping().send().await?;
+        assert_eq!(response.status_code(), StatusCode::OK);
+    }
+    tokens.invalidate().await?;
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec![
+            format!("get password - {}", basic("elastic", "changeme")),
+            r#"invalidate {"token":"token-0"}"#.to_string(),
+            r#"invalidate {"refresh_token":"refresh-0"}"#.to_string(),
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn token_credentials_provider_with_credentialed_client() -> Result<(), failure::Error> {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let server = token_server(Some(1200), log.clone());
+    let url = format!("http://{}", server.addr());
+
+    // the password grant is authenticated with its own credentials, not the client's
+    let token_client = client::create(
+        client::create_builder(&url).auth(Credentials::Basic("admin".into(), "secret".into())),
+    );
+    let tokens = TokenCredentialsProvider::password(token_client, "elastic", "changeme");
+    let builder = client::create_builder(&url).credentials_provider(tokens);
+    let client = client::create(builder);
+
+    let response = client.ping().send().await?;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec![format!("get password - {}", basic("elastic", "changeme"))]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn token_credentials_provider_refreshes_before_expiry() -> Result<(), failure::Error> {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let server = token_server(Some(0), log.clone());
+    let url = format!("http://{}", server.addr());
+
+    let tokens = TokenCredentialsProvider::refresh_token(client::create_for_url(&url), "initial");
+    let builder = client::create_builder(&url).credentials_provider(tokens);
+    let client = client::create(builder);
+
+    for _ in 0..2 {
+        let response = client.ping().send().await?;
+        assert_eq!(response.status_code(), StatusCode::OK);
+    }
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec!["get refresh_token initial ", "get refresh_token refresh-0 "]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn token_credentials_provider_without_expires_in() -> Result<(), failure::Error> {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let server = token_server(None, log.clone());
+    let url = format!("http://{}", server.addr());
+
+    let tokens = TokenCredentialsProvider::refresh_token(client::create_for_url(&url), "initial");
+    let builder = client::create_builder(&url).credentials_provider(tokens);
+    let client = client::create(builder);
+
+    for _ in 0..2 {
+        let response = client.ping().send().await?;
+        assert_eq!(response.status_code(), StatusCode::OK);
+    }
+
+    // the access token is valid for the default lifetime of access tokens
+    assert_eq!(*log.lock().unwrap(), vec!["get refresh_token initial "]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn token_credentials_provider_refreshes_on_unauthorized() -> Result<(), failure::Error> {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let server = token_server(Some(1200), log.clone());
+    let url = format!("http://{}", server.addr());
+
+    let tokens = TokenCredentialsProvider::refresh_token(client::create_for_url(&url), "initial");
+    let builder = client::create_builder(&url).credentials_provider(tokens);
+    let client = client::create(builder);
+    let _ = client.ping().send().await?;
+
+    // a token issued elsewhere supersedes the access token of the provider
+    let _ = client::create_for_url(&url)
+        .security()
+        .get_token()
+        .body(json!({ "grant_type": "refresh_token", "refresh_token": "other" }))
+        .send()
+        .await?;
+
+    let response = client.ping().send().await?;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec![
+            "get refresh_token initial ",
+            "get refresh_token other ",
+            "get refresh_token refresh-0 "
+        ]
+    );
+    Ok(())
+}
+
+/// A server that creates API keys, and accepts only API calls authenticated with
+/// the created API key, logging the API key API calls it receives
+fn api_key_server(log: Arc<Mutex<Vec<String>>>) -> server::Server {
+    server::http(move |req| {
+        let log = log.clone();
+        async move {
+            let authorization = req
+                .headers()
+                .get("authorization")
+                .map(|v| v.to_str().unwrap().to_string())
+                .unwrap_or_default();
+            let method = req.method().clone();
+            let path = req.uri().path().to_string();
+            let bytes = hyper::body::to_bytes(req.into_body()).await.unwrap();
+
+            if path == "/_security/api_key" {
+                let body: Value = serde_json::from_slice(&bytes).unwrap();
+                log.lock().unwrap().push(format!("{} {}", method, body));
+                let response = if method == Method::POST {
+                    json!({ "id": "key-id", "name": body["name"], "api_key": "key-secret" })
+                } else {
+                    json!({ "invalidated_api_keys": [body["id"]] })
+                };
+                return http::Response::new(response.to_string().into());
+            }
+
+            let status = if authorization == api_key("key-id", "key-secret") {
+                200
+            } else {
+                401
+            };
+            http::Response::builder()
+                .status(status)
+                .body(hyper::Body::empty())
+                .unwrap()
+        }
+    })
+}
+
+fn api_key(id: &str, api_key: &str) -> String {
+    let mut header_value = b"ApiKey ".to_vec();
+    {
+        let mut encoder = Base64Encoder::new(&mut header_value, base64::STANDARD);
+        write!(encoder, "{}:{}", id, api_key).unwrap();
+    }
+    String::from_utf8(header_value).unwrap()
+}
+
+#[tokio::test]
+async fn scoped_client_invalidates_api_key() -> Result<(), failure::Error> {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let server = api_key_server(log.clone());
+    let client = client::create_for_url(format!("http://{}", server.addr()).as_ref());
+
+    let role_descriptors =
+        json!({ "reader": { "indices": [{ "names": ["a"], "privileges": ["read"] }] } });
+    let scoped = client
+        .create_scoped_client("job", role_descriptors.clone(), Some("1h"))
+        .await?;
+    assert_eq!(scoped.api_key_id(), "key-id");
+
+    let response = scoped.ping().send().await?;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let response = client.ping().send().await?;
+    assert_eq!(response.status_code(), StatusCode::UNAUTHORIZED);
+
+    scoped.invalidate().await?;
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec![
+            format!(
+                "POST {}",
+                json!({ "expiration": "1h", "name": "job", "role_descriptors": role_descriptors })
+            ),
+            r#"DELETE {"id":"key-id"}"#.to_string(),
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn scoped_client_invalidates_api_key_when_dropped() -> Result<(), failure::Error> {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let server = api_key_server(log.clone());
+    let client = client::create_for_url(format!("http://{}", server.addr()).as_ref());
+
+    {
+        let scoped = client.create_scoped_client("job", json!({}), None).await?;
+        let response = scoped.ping().send().await?;
+        assert_eq!(response.status_code(), StatusCode::OK);
+    }
+
+    for _ in 0..50 {
+        if log.lock().unwrap().len() == 2 {
+            break;
+        }
+        tokio::time::delay_for(Duration::from_millis(10)).await;
+    }
+
+    assert_eq!(
+        log.lock().unwrap().last().unwrap(),
+        r#"DELETE {"id":"key-id"}"#
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn scoped_client_invalidates_api_key_when_dropped_outside_runtime(
+) -> Result<(), failure::Error> {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let server = api_key_server(log.clone());
+    let client = client::create_for_url(format!("http://{}", server.addr()).as_ref());
+
+    let scoped = client.create_scoped_client("job", json!({}), None).await?;
+    std::thread::spawn(move || drop(scoped)).join().unwrap();
+
+    for _ in 0..50 {
+        if log.lock().unwrap().len() == 2 {
+            break;
+        }
+        tokio::time::delay_for(Duration::from_millis(10)).await;
+    }
+
+    assert_eq!(
+        log.lock().unwrap().last().unwrap(),
+        r#"DELETE {"id":"key-id"}"#
+    );
+    Ok(())
+}
+
 // TODO: test PKI authentication. Could configure a HttpsConnector, maybe using https://github.com/sfackler/hyper-openssl?, or send to PKI configured Elasticsearch.
 //#[tokio::test]
 //async fn client_certificate() -> Result<(), failure::Error> {
@@ -117,3 +565,30 @@ async fn bearer_header() -> Result<(), failure::Error> {
 //
 //    Ok(())
 //}
+
+#[tokio::test]
+async fn file_credentials_are_cached_for_check_interval() -> Result<(), failure::Error> {
+    let path = std::env::temp_dir().join(format!(
+        "elasticsearch-rs-credentials-{}",
+        std::process::id()
+    ));
+    std::fs::write(&path, "first-token")?;
+
+    let provider = FileCredentialsProvider::bearer(&path).check_interval(Duration::from_secs(60));
+    let bearer = |credentials| match credentials {
+        Credentials::Bearer(token) => token,
+        _ => panic!("expected bearer credentials"),
+    };
+    assert_eq!(bearer(provider.credentials().await?), "first-token");
+
+    // the file is not checked again within the interval
+    std::fs::write(&path, "second-token")?;
+    assert_eq!(bearer(provider.credentials().await?), "first-token");
+
+    // a refresh reads the file again
+    assert!(provider.refresh().await?);
+    assert_eq!(bearer(provider.credentials().await?), "second-token");
+
+    let _ = std::fs::remove_file(&path);
+    Ok(())
+}
@@ -0,0 +1,87 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+#![cfg(feature = "blocking")]
+pub mod common;
+use common::*;
+
+use elasticsearch::{
+    blocking::Elasticsearch,
+    http::{headers::HeaderMap, Method, StatusCode},
+    SearchParts,
+};
+use serde_json::{json, Value};
+
+#[test]
+fn send_blocking() -> Result<(), failure::Error> {
+    let server = server::http(move |req| async move {
+        assert_eq!(req.method(), hyper::Method::POST);
+        assert_eq!(req.uri().path(), "/tweets/_search");
+        http::Response::new(r#"{"hits":{"total":{"value":1}}}"#.into())
+    });
+
+    let client: Elasticsearch =
+        client::create_for_url(format!("http://{}", server.addr()).as_ref()).into();
+    let response = client
+        .search(SearchParts::Index(&["tweets"]))
+        .body(json!({"query": {"match_all": {}}}))
+        .send_blocking()?;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.method(), Method::Post);
+    let body = response.json::<Value>()?;
+    assert_eq!(body["hits"]["total"]["value"], json!(1));
+
+    Ok(())
+}
+
+#[test]
+fn send_blocking_from_namespace_client() -> Result<(), failure::Error> {
+    let server = server::http(move |req| async move {
+        assert_eq!(req.uri().path(), "/_cat/health");
+        http::Response::new("green".into())
+    });
+
+    let client: Elasticsearch =
+        client::create_for_url(format!("http://{}", server.addr()).as_ref()).into();
+    let response = client.cat().health().send_blocking()?;
+
+    assert_eq!(response.text()?, "green");
+    Ok(())
+}
+
+#[test]
+fn send_on_client() -> Result<(), failure::Error> {
+    let server = server::http(move |req| async move {
+        assert_eq!(req.uri().path(), "/_cluster/health");
+        http::Response::default()
+    });
+
+    let client: Elasticsearch =
+        client::create_for_url(format!("http://{}", server.addr()).as_ref()).into();
+    let response = client.send(
+        Method::Get,
+        "/_cluster/health",
+        HeaderMap::new(),
+        Option::<&Value>::None,
+        Option::<&str>::None,
+    )?;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    Ok(())
+}
@@ -0,0 +1,175 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+#![cfg(feature = "test-util")]
+pub mod common;
+use common::*;
+
+use elasticsearch::{
+    cat::CatIndicesParts,
+    http::{
+        cassette::Cassette,
+        transport::{SingleNodeConnectionPool, TransportBuilder},
+        StatusCode,
+    },
+    Elasticsearch, SearchParts,
+};
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use url::Url;
+
+fn cassette_path(name: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir()
+        .join("elasticsearch-rs-cassettes")
+        .join(format!("{}-{}.json", name, std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+#[tokio::test]
+async fn record_and_replay() -> Result<(), failure::Error> {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let server = {
+        let requests = requests.clone();
+        server::http(move |req| {
+            let requests = requests.clone();
+            async move {
+                requests.fetch_add(1, Ordering::SeqCst);
+                assert_eq!(req.uri().path(), "/tweets/_search");
+                http::Response::builder()
+                    .header("content-type", "application/json")
+                    .body(r#"{"hits":{"total":{"value":1}}}"#.into())
+                    .unwrap()
+            }
+        })
+    };
+
+    let path = cassette_path("record_and_replay");
+    let url = format!("http://{}", server.addr());
+
+    let cassette = Cassette::new(&path)?;
+    assert!(cassette.is_recording());
+    let builder = client::create_builder(&url).cassette(cassette.clone());
+    let client = client::create(builder);
+    let response = client
+        .search(SearchParts::Index(&["tweets"]))
+        .body(json!({"query": {"match": {"user": "kimchy"}}, "size": 10}))
+        .from(5)
+        .send()
+        .await?;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    cassette.save()?;
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+    // replay with the query string parameters and body keys in a different order
+    let cassette = Cassette::new(&path)?;
+    assert!(!cassette.is_recording());
+    let builder = client::create_builder(&url).cassette(cassette.clone());
+    let client = client::create(builder);
+    let response = client
+        .search(SearchParts::Index(&["tweets"]))
+        .from(5)
+        .body(json!({"size": 10, "query": {"match": {"user": "kimchy"}}}))
+        .send()
+        .await?;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.content_type(), "application/json");
+    let body = response.json::<Value>().await?;
+    assert_eq!(body["hits"]["total"]["value"], json!(1));
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+    let _ = std::fs::remove_file(&path);
+    Ok(())
+}
+
+#[tokio::test]
+async fn replay_unrecorded_request_fails() -> Result<(), failure::Error> {
+    let path = cassette_path("replay_unrecorded_request_fails");
+    std::fs::create_dir_all(path.parent().unwrap())?;
+    std::fs::write(&path, "[]")?;
+
+    let cassette = Cassette::replay(&path)?;
+    let transport = TransportBuilder::default().http_client(cassette).build()?;
+    let client = Elasticsearch::new(transport);
+    let result = client.ping().send().await;
+    assert!(result.is_err());
+
+    let _ = std::fs::remove_file(&path);
+    Ok(())
+}
+
+#[tokio::test]
+async fn record_and_replay_binary_body() -> Result<(), failure::Error> {
+    let server = server::http(move |_| async move {
+        http::Response::builder()
+            .header("content-type", "text/plain; charset=ISO-8859-1")
+            .body(b"caf\xe9"[..].into())
+            .unwrap()
+    });
+
+    let path = cassette_path("record_and_replay_binary_body");
+    let url = format!("http://{}", server.addr());
+
+    let cassette = Cassette::new(&path)?;
+    let builder = client::create_builder(&url).cassette(cassette.clone());
+    let client = client::create(builder);
+    let response = client.cat().indices(CatIndicesParts::None).send().await?;
+    assert_eq!(response.text().await?, "café");
+    cassette.save()?;
+
+    let recorded: Value = serde_json::from_slice(&std::fs::read(&path)?)?;
+    assert_eq!(recorded[0]["response"]["body_encoding"], json!("base64"));
+
+    let cassette = Cassette::replay(&path)?;
+    let builder = client::create_builder(&url).cassette(cassette);
+    let client = client::create(builder);
+    let response = client.cat().indices(CatIndicesParts::None).send().await?;
+    assert_eq!(response.text().await?, "café");
+
+    let _ = std::fs::remove_file(&path);
+    Ok(())
+}
+
+#[tokio::test]
+async fn record_with_transport_options() -> Result<(), failure::Error> {
+    // the server is the proxy through which the node is addressed
+    let server = server::http(move |req| async move {
+        assert_eq!(req.uri().to_string(), "http://es.example:9200/");
+        http::Response::default()
+    });
+
+    let path = cassette_path("record_with_transport_options");
+    let cassette = Cassette::record(&path);
+    let transport = TransportBuilder::new(SingleNodeConnectionPool::new(Url::parse(
+        "http://es.example:9200",
+    )?))
+    .http_proxy(Url::parse(&format!("http://{}", server.addr()))?)
+    .cassette(cassette.clone())
+    .build()?;
+    let client = Elasticsearch::new(transport);
+    let response = client.ping().send().await?;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    cassette.save()?;
+
+    let recorded: Value = serde_json::from_slice(&std::fs::read(&path)?)?;
+    assert_eq!(recorded[0]["request"]["method"], json!("HEAD"));
+
+    let _ = std::fs::remove_file(&path);
+    Ok(())
+}
@@ -20,20 +20,35 @@ pub mod common;
 use common::*;
 
 use elasticsearch::{
+    auth::Credentials,
     http::{
+        client::{BoxFuture, HttpClient, HttpError, HttpResponse},
         headers::{
             HeaderMap, HeaderName, HeaderValue, ACCEPT, CONTENT_TYPE, DEFAULT_ACCEPT,
-            DEFAULT_CONTENT_TYPE, X_OPAQUE_ID,
+            DEFAULT_CONTENT_TYPE, TRACEPARENT, X_OPAQUE_ID,
         },
+        metrics::{ErrorKind, Outcome},
+        middleware::Middleware,
+        request::{JsonBody, Request, RequestOptions},
+        response::Response,
+        retry::RetryPolicy,
+        selector::WeightedSelector,
+        trace_context::{TraceContext, TraceContextMiddleware},
+        transport::{SniffingConnectionPool, StaticNodeListConnectionPool, TransportBuilder},
         StatusCode,
     },
     params::TrackTotalHits,
-    SearchParts,
+    BulkParts, Elasticsearch, Error, SearchParts,
 };
 
 use crate::common::client::index_documents;
 use hyper::Method;
 use serde_json::{json, Value};
+use std::io::Read;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use url::Url;
 
 #[tokio::test]
 async fn default_user_agent_content_type_accept_headers() -> Result<(), failure::Error> {
@@ -358,3 +373,747 @@ async fn byte_slice_body() -> Result<(), failure::Error> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn static_node_list_pool_skips_dead_node() -> Result<(), failure::Error> {
+    let server = server::http(move |_| async move { http::Response::default() });
+
+    // nothing listens on port 1, so connections to it are refused
+    let urls = vec![
+        Url::parse("http://127.0.0.1:1")?,
+        Url::parse(format!("http://{}", server.addr()).as_ref())?,
+    ];
+    let conn_pool = StaticNodeListConnectionPool::new(urls)?;
+    let client = Elasticsearch::new(TransportBuilder::new(conn_pool).build()?);
+
+    let response = client.ping().send().await;
+    assert!(response.is_err());
+
+    for _ in 0..3 {
+        let response = client.ping().send().await?;
+        assert_eq!(response.status_code(), StatusCode::OK);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sniffing_pool_discovers_nodes() -> Result<(), failure::Error> {
+    let publish_address = Arc::new(Mutex::new(String::new()));
+    let sniffed = Arc::new(AtomicUsize::new(0));
+
+    let server = {
+        let publish_address = publish_address.clone();
+        let sniffed = sniffed.clone();
+        server::http(move |req| {
+            let publish_address = publish_address.lock().unwrap().clone();
+            let sniffed = sniffed.clone();
+            async move {
+                if req.uri().path() == "/_nodes/http" {
+                    sniffed.fetch_add(1, Ordering::SeqCst);
+                    let body = json!({
+                        "nodes": {
+                            "master": {
+                                "roles": ["master"],
+                                "http": { "publish_address": "127.0.0.1:1" }
+                            },
+                            "data": {
+                                "roles": ["data", "ingest"],
+                                "http": { "publish_address": publish_address }
+                            }
+                        }
+                    });
+                    http::Response::new(body.to_string().into())
+                } else {
+                    http::Response::default()
+                }
+            }
+        })
+    };
+
+    *publish_address.lock().unwrap() = format!("localhost/{}", server.addr());
+
+    let url = Url::parse(format!("http://{}", server.addr()).as_ref())?;
+    let conn_pool = SniffingConnectionPool::new(vec![url])?;
+    let client = Elasticsearch::new(TransportBuilder::new(conn_pool.clone()).build()?);
+
+    for _ in 0..3 {
+        let response = client.ping().send().await?;
+        assert_eq!(response.status_code(), StatusCode::OK);
+    }
+
+    // the cluster is sniffed in the background
+    for _ in 0..100 {
+        if conn_pool.connections()[0].url().host_str() == Some("localhost") {
+            break;
+        }
+        tokio::time::delay_for(Duration::from_millis(50)).await;
+    }
+
+    assert_eq!(sniffed.load(Ordering::SeqCst), 1);
+    let connections = conn_pool.connections();
+    assert_eq!(connections.len(), 1);
+    assert_eq!(connections[0].url().host_str(), Some("localhost"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sniffing_does_not_hold_up_api_calls() -> Result<(), failure::Error> {
+    let server = server::http(move |req| async move {
+        if req.uri().path() == "/_nodes/http" {
+            tokio::time::delay_for(Duration::from_secs(2)).await;
+        }
+        http::Response::default()
+    });
+
+    let url = Url::parse(format!("http://{}", server.addr()).as_ref())?;
+    let conn_pool = SniffingConnectionPool::new(vec![url])?;
+    let client = Elasticsearch::new(TransportBuilder::new(conn_pool).build()?);
+
+    let response = tokio::time::timeout(Duration::from_secs(1), client.ping().send()).await??;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sniffing_pool_reports_sniff_errors() -> Result<(), failure::Error> {
+    let server = server::http(move |req| async move {
+        if req.uri().path() == "/_nodes/http" {
+            http::Response::builder()
+                .status(500)
+                .body("".into())
+                .unwrap()
+        } else {
+            http::Response::default()
+        }
+    });
+
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    let url = Url::parse(format!("http://{}", server.addr()).as_ref())?;
+    let conn_pool = {
+        let errors = errors.clone();
+        SniffingConnectionPool::new(vec![url])?
+            .sniff_error(move |err| errors.lock().unwrap().push(err.to_string()))
+    };
+    let client = Elasticsearch::new(TransportBuilder::new(conn_pool).build()?);
+
+    let response = client.ping().send().await?;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    for _ in 0..100 {
+        if !errors.lock().unwrap().is_empty() {
+            break;
+        }
+        tokio::time::delay_for(Duration::from_millis(50)).await;
+    }
+    assert_eq!(errors.lock().unwrap().len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn retry_on_status_code() -> Result<(), failure::Error> {
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let server = {
+        let attempts = attempts.clone();
+        server::http(move |req| {
+            let attempts = attempts.clone();
+            async move {
+                assert_eq!(req.method(), Method::POST);
+                let body = hyper::body::to_bytes(req.into_body()).await.unwrap();
+                assert_eq!(&body[..], b"{\"query\":{\"match_all\":{}}}");
+                if attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+                    http::Response::builder()
+                        .status(503)
+                        .body(hyper::Body::empty())
+                        .unwrap()
+                } else {
+                    http::Response::default()
+                }
+            }
+        })
+    };
+
+    let retry_policy = RetryPolicy::default().initial_backoff(Duration::from_millis(1));
+    let builder = client::create_builder(format!("http://{}", server.addr()).as_ref())
+        .retry_policy(retry_policy);
+    let client = client::create(builder);
+    let response = client
+        .search(SearchParts::None)
+        .body(json!({"query":{"match_all":{}}}))
+        .send()
+        .await?;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn retry_on_status_code_stops_at_max_retries() -> Result<(), failure::Error> {
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let server = {
+        let attempts = attempts.clone();
+        server::http(move |_| {
+            let attempts = attempts.clone();
+            async move {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                http::Response::builder()
+                    .status(502)
+                    .body(hyper::Body::empty())
+                    .unwrap()
+            }
+        })
+    };
+
+    let retry_policy = RetryPolicy::default()
+        .max_retries(2)
+        .initial_backoff(Duration::from_millis(1));
+    let builder = client::create_builder(format!("http://{}", server.addr()).as_ref())
+        .retry_policy(retry_policy);
+    let client = client::create(builder);
+    let response = client.ping().send().await?;
+
+    assert_eq!(response.status_code(), StatusCode::BAD_GATEWAY);
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn retry_on_connection_error_moves_to_next_node() -> Result<(), failure::Error> {
+    let server = server::http(move |_| async move { http::Response::default() });
+
+    let urls = vec![
+        Url::parse("http://127.0.0.1:1")?,
+        Url::parse(format!("http://{}", server.addr()).as_ref())?,
+    ];
+    let conn_pool = StaticNodeListConnectionPool::new(urls)?;
+    let retry_policy = RetryPolicy::default().initial_backoff(Duration::from_millis(1));
+    let transport = TransportBuilder::new(conn_pool)
+        .retry_policy(retry_policy)
+        .build()?;
+    let client = Elasticsearch::new(transport);
+
+    let response = client.ping().send().await?;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn request_timeout() -> Result<(), failure::Error> {
+    let server = server::http(move |_| async move {
+        tokio::time::delay_for(Duration::from_secs(1)).await;
+        http::Response::default()
+    });
+
+    let client = client::create_for_url(format!("http://{}", server.addr()).as_ref());
+    let result = client
+        .ping()
+        .request_timeout(Duration::from_millis(100))
+        .send()
+        .await;
+
+    match result {
+        Ok(_) => panic!("expected request to time out"),
+        Err(e) => assert!(e.is_timeout()),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn send_with_options_timeout() -> Result<(), failure::Error> {
+    let server = server::http(move |_| async move {
+        tokio::time::delay_for(Duration::from_secs(1)).await;
+        http::Response::default()
+    });
+
+    let client = client::create_for_url(format!("http://{}", server.addr()).as_ref());
+    let result = client
+        .send_with_options(
+            elasticsearch::http::Method::Get,
+            "/",
+            HeaderMap::new(),
+            Option::<&Value>::None,
+            Option::<&[u8]>::None,
+            &RequestOptions::new().timeout(Duration::from_millis(100)),
+        )
+        .await;
+
+    match result {
+        Ok(_) => panic!("expected request to time out"),
+        Err(e) => assert!(e.is_timeout()),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn transport_timeout() -> Result<(), failure::Error> {
+    let server = server::http(move |_| async move {
+        tokio::time::delay_for(Duration::from_secs(1)).await;
+        http::Response::default()
+    });
+
+    let builder = client::create_builder(format!("http://{}", server.addr()).as_ref())
+        .timeout(Duration::from_millis(100));
+    let client = client::create(builder);
+    let result = client.ping().send().await;
+
+    match result {
+        Ok(_) => panic!("expected request to time out"),
+        Err(e) => assert!(e.is_timeout()),
+    }
+
+    let response = client
+        .ping()
+        .request_timeout(Duration::from_secs(5))
+        .send()
+        .await?;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn request_body_compression() -> Result<(), failure::Error> {
+    let server = server::http(move |req| async move {
+        assert_eq!(req.headers()["content-encoding"], "gzip");
+        let bytes = hyper::body::to_bytes(req.into_body()).await.unwrap();
+        let mut decoder = flate2::read::GzDecoder::new(&bytes[..]);
+        let mut body = String::new();
+        decoder.read_to_string(&mut body).unwrap();
+        let json: Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(json["query"]["match"]["title"].as_str(), Some("compressed"));
+        http::Response::default()
+    });
+
+    let builder = client::create_builder(format!("http://{}", server.addr()).as_ref())
+        .request_body_compression(true)
+        .request_body_compression_threshold(0);
+    let client = client::create(builder);
+    let response = client
+        .search(SearchParts::None)
+        .body(json!({
+            "query": {
+                "match": {
+                    "title": "compressed"
+                }
+            }
+        }))
+        .send()
+        .await?;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    Ok(())
+}
+
+#[tokio::test]
+async fn request_body_compression_below_threshold() -> Result<(), failure::Error> {
+    let server = server::http(move |req| async move {
+        assert!(req.headers().get("content-encoding").is_none());
+        let bytes = hyper::body::to_bytes(req.into_body()).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["query"]["match_all"], json!({}));
+        http::Response::default()
+    });
+
+    let builder = client::create_builder(format!("http://{}", server.addr()).as_ref())
+        .request_body_compression(true);
+    let client = client::create(builder);
+    let response = client
+        .search(SearchParts::None)
+        .body(json!({
+            "query": {
+                "match_all": {}
+            }
+        }))
+        .send()
+        .await?;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    Ok(())
+}
+
+#[derive(Debug)]
+struct RecordingMiddleware {
+    name: &'static str,
+    calls: Arc<Mutex<Vec<String>>>,
+}
+
+impl Middleware for RecordingMiddleware {
+    fn on_request(&self, request: &mut Request) -> Result<(), Error> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("{} request {}", self.name, request.url().path()));
+        request.headers_mut().insert(
+            HeaderName::from_static("x-middleware"),
+            HeaderValue::from_static(self.name),
+        );
+        Ok(())
+    }
+
+    fn on_response(&self, _request: &Request, response: &Response) {
+        self.calls.lock().unwrap().push(format!(
+            "{} response {}",
+            self.name,
+            response.status_code().as_u16()
+        ));
+    }
+}
+
+#[tokio::test]
+async fn middleware_chain() -> Result<(), failure::Error> {
+    let server = server::http(move |req| async move {
+        assert_eq!(req.headers()["x-middleware"], "second");
+        http::Response::default()
+    });
+
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let builder = client::create_builder(format!("http://{}", server.addr()).as_ref())
+        .middleware(RecordingMiddleware {
+            name: "first",
+            calls: calls.clone(),
+        })
+        .middleware(RecordingMiddleware {
+            name: "second",
+            calls: calls.clone(),
+        });
+    let client = client::create(builder);
+    let response = client.ping().send().await?;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(
+        *calls.lock().unwrap(),
+        vec![
+            "first request /",
+            "second request /",
+            "second response 200",
+            "first response 200"
+        ]
+    );
+
+    Ok(())
+}
+
+#[derive(Debug)]
+struct RejectingMiddleware;
+
+impl Middleware for RejectingMiddleware {
+    fn on_request(&self, _request: &mut Request) -> Result<(), Error> {
+        Err(Error::from(HttpError::other("rejected")))
+    }
+}
+
+#[tokio::test]
+async fn middleware_error_fails_api_call() -> Result<(), failure::Error> {
+    let server = server::http(move |_| async move {
+        panic!("request should not be sent");
+    });
+
+    let builder = client::create_builder(format!("http://{}", server.addr()).as_ref())
+        .middleware(RejectingMiddleware);
+    let client = client::create(builder);
+    let result = client.ping().send().await;
+
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[derive(Debug, Default)]
+struct FakeHttpClient {
+    requests: Mutex<Vec<Request>>,
+}
+
+impl HttpClient for FakeHttpClient {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<HttpResponse, HttpError>> {
+        let url = request.url().clone();
+        self.requests.lock().unwrap().push(request);
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static(DEFAULT_CONTENT_TYPE));
+        let response =
+            HttpResponse::from_bytes(StatusCode::OK, headers, url, r#"{"acknowledged":true}"#);
+        Box::pin(async move { Ok(response) })
+    }
+}
+
+#[tokio::test]
+async fn custom_http_client() -> Result<(), failure::Error> {
+    let http_client = Arc::new(FakeHttpClient::default());
+    let builder = TransportBuilder::default().http_client(http_client.clone());
+    let client = client::create(builder);
+    let response = client
+        .search(SearchParts::Index(&["index"]))
+        .q("title:fake")
+        .body(json!({}))
+        .send()
+        .await?;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body = response.json::<Value>().await?;
+    assert_eq!(body["acknowledged"], json!(true));
+
+    let requests = http_client.requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method(), elasticsearch::http::Method::Post);
+    assert_eq!(requests[0].url().path(), "/index/_search");
+    assert_eq!(requests[0].url().query(), Some("q=title%3Afake"));
+    assert_eq!(requests[0].headers()[ACCEPT], DEFAULT_ACCEPT);
+    assert_eq!(requests[0].body().map(|b| &b[..]), Some(&b"{}"[..]));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn error_for_status_code() -> Result<(), failure::Error> {
+    let server = server::http(move |_| async move {
+        http::Response::builder()
+            .status(404)
+            .body(hyper::Body::empty())
+            .unwrap()
+    });
+
+    let client = client::create_for_url(format!("http://{}", server.addr()).as_ref());
+    let response = client.ping().send().await?;
+
+    match response.error_for_status_code() {
+        Ok(_) => panic!("expected an error status code"),
+        Err(e) => {
+            assert_eq!(e.status_code(), Some(StatusCode::NOT_FOUND));
+            assert!(e
+                .to_string()
+                .starts_with("HTTP status client error (404 Not Found)"));
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn trace_context_headers() -> Result<(), failure::Error> {
+    let server = server::http(move |req| async move {
+        assert_eq!(
+            req.headers()["traceparent"],
+            "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01"
+        );
+        assert_eq!(req.headers()["tracestate"], "congo=t61rcWkgMzE");
+        assert_eq!(
+            req.headers()["x-opaque-id"],
+            "0af7651916cd43dd8448eb211c80319c"
+        );
+        http::Response::default()
+    });
+
+    let trace_context = TraceContextMiddleware::new(|| {
+        TraceContext::parse("00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01")
+            .ok()
+            .map(|c| c.trace_state("congo=t61rcWkgMzE"))
+    })
+    .opaque_id_from_trace_id(true);
+    let builder = client::create_builder(format!("http://{}", server.addr()).as_ref())
+        .middleware(trace_context);
+    let client = client::create(builder);
+    let _response = client.ping().send().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn trace_context_does_not_override_call_headers() -> Result<(), failure::Error> {
+    let server = server::http(move |req| async move {
+        assert_eq!(
+            req.headers()["traceparent"],
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-00"
+        );
+        assert_eq!(req.headers()["x-opaque-id"], "foo");
+        http::Response::default()
+    });
+
+    let trace_context = TraceContextMiddleware::new(|| {
+        TraceContext::parse("00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01").ok()
+    })
+    .opaque_id_from_trace_id(true);
+    let builder = client::create_builder(format!("http://{}", server.addr()).as_ref())
+        .middleware(trace_context);
+    let client = client::create(builder);
+    let _response = client
+        .ping()
+        .header(
+            HeaderName::from_static(TRACEPARENT),
+            HeaderValue::from_static("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-00"),
+        )
+        .header(
+            HeaderName::from_static(X_OPAQUE_ID),
+            HeaderValue::from_static("foo"),
+        )
+        .send()
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn no_trace_context_headers_outside_trace() -> Result<(), failure::Error> {
+    let server = server::http(move |req| async move {
+        assert!(req.headers().get("traceparent").is_none());
+        assert!(req.headers().get("x-opaque-id").is_none());
+        http::Response::default()
+    });
+
+    let trace_context = TraceContextMiddleware::new(|| None).opaque_id_from_trace_id(true);
+    let builder = client::create_builder(format!("http://{}", server.addr()).as_ref())
+        .middleware(trace_context);
+    let client = client::create(builder);
+    let _response = client.ping().send().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn metrics_not_collected_by_default() -> Result<(), failure::Error> {
+    let server = server::http(move |_| async move { http::Response::default() });
+
+    let client = client::create_for_url(format!("http://{}", server.addr()).as_ref());
+    let _ = client.ping().send().await?;
+
+    assert!(client.metrics_snapshot().is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn metrics_count_requests_by_endpoint_and_outcome() -> Result<(), failure::Error> {
+    let server = server::http(move |req| async move {
+        match req.uri().path() {
+            "/_search" => http::Response::default(),
+            _ => http::Response::builder()
+                .status(404)
+                .body("".into())
+                .unwrap(),
+        }
+    });
+
+    let builder =
+        client::create_builder(format!("http://{}", server.addr()).as_ref()).metrics(true);
+    let client = client::create(builder);
+    for _ in 0..2 {
+        let _ = client.search(SearchParts::None).send().await?;
+    }
+    let _ = client.ping().send().await?;
+
+    let snapshot = client.metrics_snapshot().unwrap();
+    assert_eq!(snapshot.request_count("search"), 2);
+    assert_eq!(snapshot.request_count("ping"), 1);
+
+    let (labels, count) = snapshot
+        .requests()
+        .find(|(labels, _)| labels.endpoint() == "ping")
+        .unwrap();
+    assert_eq!(count, 1);
+    assert_eq!(labels.outcome(), Outcome::Status(4));
+    assert_eq!(labels.node(), format!("http://{}/", server.addr()));
+
+    assert_eq!(snapshot.latency("search").unwrap().count(), 2);
+    assert_eq!(snapshot.latency("ping").unwrap().count(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn metrics_count_connection_errors() -> Result<(), failure::Error> {
+    // no server listening on the port
+    let builder = client::create_builder("http://127.0.0.1:1").metrics(true);
+    let client = client::create(builder);
+    let result = client.ping().send().await;
+    assert!(result.is_err());
+
+    let snapshot = client.metrics_snapshot().unwrap();
+    let (labels, count) = snapshot.requests().next().unwrap();
+    assert_eq!(count, 1);
+    assert_eq!(labels.error_kind(), Some(ErrorKind::Connection));
+    Ok(())
+}
+
+#[tokio::test]
+async fn metrics_bulk_request_size() -> Result<(), failure::Error> {
+    let server = server::http(move |_| async move { http::Response::default() });
+
+    let builder =
+        client::create_builder(format!("http://{}", server.addr()).as_ref()).metrics(true);
+    let client = client::create(builder);
+
+    let body: Vec<JsonBody<_>> = vec![
+        json!({"index": {"_id": "1"}}).into(),
+        json!({"title": "metrics"}).into(),
+    ];
+    let _ = client
+        .bulk(BulkParts::Index("posts"))
+        .body(body)
+        .send()
+        .await?;
+
+    let snapshot = client.metrics_snapshot().unwrap();
+    let bulk_size = snapshot.bulk_size();
+    assert_eq!(bulk_size.count(), 1);
+    assert!(bulk_size.sum() > 0.0);
+
+    let prometheus = snapshot.to_prometheus();
+    assert!(prometheus.contains("elasticsearch_client_bulk_request_size_bytes_count 1"));
+    assert!(prometheus.contains("endpoint=\"bulk\""));
+    Ok(())
+}
+
+#[tokio::test]
+async fn proxy_authorization_header() -> Result<(), failure::Error> {
+    let server = server::http(move |req| async move {
+        // requests to http nodes are sent to the proxy with the absolute url of the node
+        assert_eq!(req.uri().to_string(), "http://es.example.com:9200/");
+        assert_eq!(req.headers()["proxy-authorization"], "Bearer proxy_token");
+        http::Response::default()
+    });
+
+    let builder = client::create_builder("http://es.example.com:9200")
+        .http_proxy(Url::parse(format!("http://{}", server.addr()).as_ref())?)
+        .proxy_auth(Credentials::Bearer("proxy_token".into()));
+    let client = Elasticsearch::new(builder.build()?);
+    let response = client.ping().send().await?;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    Ok(())
+}
+
+#[tokio::test]
+async fn no_proxy_hosts_are_not_proxied() -> Result<(), failure::Error> {
+    let server = server::http(move |req| async move {
+        assert_eq!(req.uri().to_string(), "/");
+        assert!(req.headers().get("proxy-authorization").is_none());
+        http::Response::default()
+    });
+
+    // a second node, addressed through the proxy, is never selected
+    let urls = vec![
+        Url::parse(&format!("http://{}", server.addr()))?,
+        Url::parse("http://es2.example:9200")?,
+    ];
+    let conn_pool = StaticNodeListConnectionPool::new(urls)?.selector(WeightedSelector::new(|c| {
+        if c.url().host_str() == Some("127.0.0.1") {
+            1
+        } else {
+            0
+        }
+    }));
+    // no server listening on the proxy port
+    let builder = TransportBuilder::new(conn_pool)
+        .proxy(Url::parse("http://127.0.0.1:1")?, None, None)
+        .proxy_authorization(HeaderValue::from_static("Custom value"))
+        .no_proxy(vec!["127.0.0.1"]);
+    let client = Elasticsearch::new(builder.build()?);
+    let response = client.ping().send().await?;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    Ok(())
+}
@@ -0,0 +1,164 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+#![cfg(feature = "test-util")]
+
+use elasticsearch::{
+    cat::CatIndicesParts,
+    http::{
+        headers::{HeaderName, HeaderValue},
+        mock::{MockResponse, MockTransport},
+        retry::RetryPolicy,
+        Method, StatusCode,
+    },
+    Elasticsearch, IndexParts, SearchParts,
+};
+use serde_json::{json, Value};
+
+#[tokio::test]
+async fn returns_canned_response() -> Result<(), failure::Error> {
+    let mock = MockTransport::new().on(
+        Method::Post,
+        "/*/_search",
+        MockResponse::new(StatusCode::OK)
+            .header(
+                HeaderName::from_static("x-custom"),
+                HeaderValue::from_static("value"),
+            )
+            .json(&json!({"hits": {"total": {"value": 1}}})),
+    );
+    let client = Elasticsearch::new(mock.transport());
+
+    let response = client
+        .search(SearchParts::Index(&["tweets"]))
+        .body(json!({"query": {"match_all": {}}}))
+        .send()
+        .await?;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.headers()["x-custom"], "value");
+    let body = response.json::<Value>().await?;
+    assert_eq!(body["hits"]["total"]["value"], json!(1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn records_requests() -> Result<(), failure::Error> {
+    let mock = MockTransport::new().on(
+        Method::Post,
+        "/*/_doc/*",
+        MockResponse::new(StatusCode::CREATED),
+    );
+    let client = Elasticsearch::new(mock.transport());
+
+    let _ = client
+        .index(IndexParts::IndexId("tweets", "1"))
+        .body(json!({"user": "kimchy"}))
+        .refresh(elasticsearch::params::Refresh::True)
+        .send()
+        .await?;
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method(), Method::Post);
+    assert_eq!(requests[0].url().path(), "/tweets/_doc/1");
+    assert_eq!(requests[0].url().query(), Some("refresh=true"));
+    let body: Value = serde_json::from_slice(requests[0].body().unwrap())?;
+    assert_eq!(body, json!({"user": "kimchy"}));
+
+    mock.clear_requests();
+    assert!(mock.requests().is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn returns_queued_responses_in_order() -> Result<(), failure::Error> {
+    let mock = MockTransport::new()
+        .on(
+            Method::Head,
+            "/",
+            MockResponse::new(StatusCode::SERVICE_UNAVAILABLE),
+        )
+        .on(Method::Head, "/", MockResponse::new(StatusCode::OK));
+    let client = Elasticsearch::new(mock.transport());
+
+    let first = client.ping().send().await?;
+    let second = client.ping().send().await?;
+    let third = client.ping().send().await?;
+
+    assert_eq!(first.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(second.status_code(), StatusCode::OK);
+    assert_eq!(third.status_code(), StatusCode::OK);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn unmatched_request_fails() -> Result<(), failure::Error> {
+    let mock = MockTransport::new();
+    let client = Elasticsearch::new(mock.transport());
+
+    let result = client.ping().send().await;
+
+    assert!(result.is_err());
+    assert_eq!(mock.requests().len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn connection_error_is_retried() -> Result<(), failure::Error> {
+    let mock = MockTransport::new()
+        .on(Method::Head, "/", MockResponse::connection_error())
+        .on(Method::Head, "/", MockResponse::new(StatusCode::OK));
+    let transport = mock
+        .transport_builder()
+        .retry_policy(RetryPolicy::default())
+        .build()?;
+    let client = Elasticsearch::new(transport);
+
+    let response = client.ping().send().await?;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(mock.requests().len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn text_is_decoded_with_content_type_charset() -> Result<(), failure::Error> {
+    let mock = MockTransport::new().on(
+        Method::Get,
+        "/_cat/indices",
+        MockResponse::new(StatusCode::OK)
+            .header(
+                HeaderName::from_static("content-type"),
+                HeaderValue::from_static("text/plain; charset=ISO-8859-1"),
+            )
+            .body(&b"caf\xe9"[..]),
+    );
+    let client = Elasticsearch::new(mock.transport());
+
+    let response = client.cat().indices(CatIndicesParts::None).send().await?;
+
+    assert_eq!(response.text().await?, "café");
+
+    Ok(())
+}
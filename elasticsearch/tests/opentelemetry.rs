@@ -0,0 +1,104 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+#![cfg(feature = "opentelemetry")]
+pub mod common;
+use common::*;
+
+use elasticsearch::http::trace_context::{TraceContext, TraceContextMiddleware};
+use opentelemetry_crate::{
+    trace::{FutureExt, SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState},
+    Context,
+};
+use std::sync::{Arc, Mutex};
+
+/// A context with a remote span of the given trace and span id
+fn span_context(trace_id: &str, span_id: &str) -> Context {
+    Context::new().with_remote_span_context(SpanContext::new(
+        TraceId::from_hex(trace_id).unwrap(),
+        SpanId::from_hex(span_id).unwrap(),
+        TraceFlags::SAMPLED,
+        true,
+        TraceState::from_key_value(vec![("congo", "t61rcWkgMzE")]).unwrap(),
+    ))
+}
+
+#[tokio::test]
+async fn traceparent_from_current_span() -> Result<(), failure::Error> {
+    let headers = Arc::new(Mutex::new(Vec::new()));
+    let server = {
+        let headers = headers.clone();
+        server::http(move |req| {
+            let headers = headers.clone();
+            async move {
+                headers.lock().unwrap().push((
+                    req.headers()["traceparent"].to_str().unwrap().to_string(),
+                    req.headers()["tracestate"].to_str().unwrap().to_string(),
+                ));
+                http::Response::default()
+            }
+        })
+    };
+
+    let builder = client::create_builder(format!("http://{}", server.addr()).as_ref())
+        .middleware(TraceContextMiddleware::default());
+    let client = client::create(builder);
+    client
+        .ping()
+        .send()
+        .with_context(span_context(
+            "0af7651916cd43dd8448eb211c80319c",
+            "b7ad6b7169203331",
+        ))
+        .await?;
+    client
+        .ping()
+        .send()
+        .with_context(span_context(
+            "4bf92f3577b34da6a3ce929d0e0e4736",
+            "00f067aa0ba902b7",
+        ))
+        .await?;
+
+    let headers = headers.lock().unwrap();
+    let first = TraceContext::parse(&headers[0].0)?;
+    let second = TraceContext::parse(&headers[1].0)?;
+    assert_eq!(first.trace_id(), "0af7651916cd43dd8448eb211c80319c");
+    assert_eq!(second.trace_id(), "4bf92f3577b34da6a3ce929d0e0e4736");
+    assert_ne!(first.parent_id(), "b7ad6b7169203331");
+    assert_ne!(first.parent_id(), second.parent_id());
+    assert!(first.sampled());
+    assert_eq!(headers[0].1, "congo=t61rcWkgMzE");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn no_traceparent_outside_span() -> Result<(), failure::Error> {
+    let server = server::http(move |req| async move {
+        assert!(req.headers().get("traceparent").is_none());
+        http::Response::default()
+    });
+
+    let builder = client::create_builder(format!("http://{}", server.addr()).as_ref())
+        .middleware(TraceContextMiddleware::default());
+    let client = client::create(builder);
+    client.ping().send().await?;
+
+    Ok(())
+}
@@ -0,0 +1,188 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+#![cfg(feature = "tracing")]
+pub mod common;
+use common::*;
+
+use elasticsearch::{
+    http::{retry::RetryPolicy, StatusCode},
+    indices::IndicesCreateParts,
+};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Event, Metadata, Subscriber,
+};
+use tracing_core::span::Current;
+
+/// A subscriber that records the fields of spans
+#[derive(Clone, Default)]
+struct SpanRecorder {
+    spans: Arc<Mutex<Vec<BTreeMap<String, String>>>>,
+    metadata: Arc<Mutex<Vec<&'static Metadata<'static>>>>,
+    entered: Arc<Mutex<Vec<Id>>>,
+}
+
+impl SpanRecorder {
+    /// The fields of the spans for API calls
+    fn api_call_spans(&self) -> Vec<BTreeMap<String, String>> {
+        self.spans
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|s| s["name"] == "elasticsearch")
+            .cloned()
+            .collect()
+    }
+}
+
+struct FieldVisitor<'a>(&'a mut BTreeMap<String, String>);
+
+impl<'a> Visit for FieldVisitor<'a> {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{:?}", value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+}
+
+impl Subscriber for SpanRecorder {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut fields = BTreeMap::new();
+        fields.insert("name".to_string(), span.metadata().name().to_string());
+        span.record(&mut FieldVisitor(&mut fields));
+        let mut spans = self.spans.lock().unwrap();
+        spans.push(fields);
+        self.metadata.lock().unwrap().push(span.metadata());
+        Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, span: &Id, values: &Record<'_>) {
+        let mut spans = self.spans.lock().unwrap();
+        let fields = &mut spans[span.into_u64() as usize - 1];
+        values.record(&mut FieldVisitor(fields));
+    }
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, _event: &Event<'_>) {}
+
+    fn enter(&self, span: &Id) {
+        self.entered.lock().unwrap().push(span.clone());
+    }
+
+    fn exit(&self, _span: &Id) {
+        self.entered.lock().unwrap().pop();
+    }
+
+    fn current_span(&self) -> Current {
+        match self.entered.lock().unwrap().last() {
+            Some(id) => {
+                let metadata = self.metadata.lock().unwrap()[id.into_u64() as usize - 1];
+                Current::new(id.clone(), metadata)
+            }
+            None => Current::none(),
+        }
+    }
+}
+
+#[tokio::test]
+async fn api_call_span() -> Result<(), failure::Error> {
+    let server = server::http(move |_| async move {
+        http::Response::builder()
+            .header("content-length", "21")
+            .body(r#"{"acknowledged":true}"#.into())
+            .unwrap()
+    });
+
+    let recorder = SpanRecorder::default();
+    let _guard = tracing::subscriber::set_default(recorder.clone());
+
+    let client = client::create_for_url(format!("http://{}", server.addr()).as_ref());
+    let response = client
+        .indices()
+        .create(IndicesCreateParts::Index("tweets"))
+        .send()
+        .await?;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    let spans = recorder.api_call_spans();
+    assert_eq!(spans.len(), 1);
+    let span = &spans[0];
+    assert_eq!(span["name"], "elasticsearch");
+    assert_eq!(span["endpoint"], "indices.create");
+    assert_eq!(span["http.method"], "Put");
+    assert_eq!(span["http.url"], format!("http://{}/", server.addr()));
+    assert_eq!(span["http.status_code"], "200");
+    assert_eq!(span["http.response_content_length"], "21");
+    assert_eq!(span["retries"], "0");
+    assert!(span.contains_key("elapsed_ms"));
+    assert!(!span.contains_key("error"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn api_call_span_records_retries() -> Result<(), failure::Error> {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let server = {
+        let requests = requests.clone();
+        server::http(move |_| {
+            let requests = requests.clone();
+            async move {
+                let status = if requests.fetch_add(1, Ordering::SeqCst) == 0 {
+                    503
+                } else {
+                    200
+                };
+                http::Response::builder()
+                    .status(status)
+                    .body(hyper::Body::empty())
+                    .unwrap()
+            }
+        })
+    };
+
+    let recorder = SpanRecorder::default();
+    let _guard = tracing::subscriber::set_default(recorder.clone());
+
+    let builder = client::create_builder(format!("http://{}", server.addr()).as_ref())
+        .retry_policy(RetryPolicy::default());
+    let client = client::create(builder);
+    let response = client.ping().send().await?;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    let spans = recorder.api_call_spans();
+    assert_eq!(spans.len(), 1);
+    assert_eq!(spans[0]["endpoint"], "ping");
+    assert_eq!(spans[0]["retries"], "1");
+    assert_eq!(spans[0]["http.status_code"], "200");
+
+    Ok(())
+}